@@ -281,7 +281,7 @@ impl ParseFragment for &'static str {
     }
 }
 
-impl<'a> ToEString for &'a str {
+impl ToEString for &str {
     #[inline]
     fn to_estring(&self) -> EString {
         EString((*self).to_string())
@@ -289,7 +289,7 @@ impl<'a> ToEString for &'a str {
 }
 
 #[cfg(feature = "aggs")]
-impl<'a> Aggregatable for &'a str {
+impl Aggregatable for &str {
     type Item = Self;
 
     #[inline]
@@ -5,12 +5,81 @@ use crate::core::EString;
 pub struct Error(pub EString, pub Reason);
 
 /// The reason for the failure to parse.
+///
+/// New reasons can be added in minor versions, so a `match` on the reason must have a wildcard
+/// arm.
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Reason {
     /// Cannot split fragment
     Split,
     /// Cannot parse fragment
     Parse,
+    /// Fragment contains an empty element
+    Empty,
+    /// Fragment contains an unexpected number of elements
+    Length {
+        /// The expected number of elements
+        expected: usize,
+        /// The actual number of elements
+        actual: usize,
+    },
+    /// Fragment contains a duplicate key or element
+    Duplicate,
+    /// Fragment contains fewer elements than required
+    TooFew {
+        /// The minimum number of elements
+        min: usize,
+        /// The actual number of elements
+        actual: usize,
+    },
+    /// Fragment contains more elements than allowed
+    TooMany {
+        /// The maximum number of elements
+        max: usize,
+        /// The actual number of elements
+        actual: usize,
+    },
+    /// Fragment doesn't contain exactly one character
+    Char,
+    /// Fragment contains zero, but the value must be non-zero
+    Zero,
+    /// Value is out of range of the type
+    Overflow,
+    /// Value is out of the allowed bounds
+    OutOfBounds,
+    /// Path doesn't exist
+    NotFound,
+    /// Path is not a file
+    NotFile,
+    /// Path is not a directory
+    NotDir,
+    /// Path is not absolute
+    NotAbsolute,
+    /// Network prefix length is greater than the number of address bits
+    Prefix,
+    /// Range start is greater than its end
+    Range,
+    /// Cannot parse the line of a multiline fragment
+    Line {
+        /// The number of the line, starting from one
+        line: usize,
+        /// The reason for the failure to parse the line
+        reason: Box<Reason>,
+    },
+    /// Required key or environment variable is missing
+    Missing,
+    /// Key is not expected
+    Unknown,
+    /// Cannot parse the value of the key
+    Field {
+        /// The key of the value
+        key: String,
+        /// The reason for the failure to parse the value
+        reason: Box<Reason>,
+    },
+    /// None of the alternatives matched. Contains errors of all alternatives in order
+    NoMatch(Vec<Error>),
 }
 
 impl std::fmt::Display for Error {
@@ -0,0 +1,11 @@
+//! Contains implementations for types of external crates (`chrono`, `time`).
+//!
+//! **NOTE**: Require the enabling the `chrono` or `time` feature.
+//!
+//! **NOTE**: These features require a newer Rust version than the MSRV of this crate.
+//!
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "time")]
+mod time;
@@ -0,0 +1,319 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+use crate::std::time::{
+    format_date, format_rfc3339, format_rfc3339_local, format_time, format_unix_timestamp,
+    parse_date, parse_date_time, parse_rfc3339_local, parse_time, parse_unix_timestamp, DateParts,
+    Rfc3339, TimeFormat, TimeParts, UnixTimestamp,
+};
+use ::chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Timelike, Utc,
+};
+
+fn naive_date((year, month, day): DateParts) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)
+}
+
+fn naive_time((hour, min, sec, nanos): TimeParts) -> Option<NaiveTime> {
+    NaiveTime::from_hms_nano_opt(hour, min, sec, nanos)
+}
+
+// formats in UTC if the offset has seconds
+fn format_rfc3339_with_offset<Tz: TimeZone>(value: &DateTime<Tz>) -> String {
+    let local = value.naive_local();
+    format_rfc3339_local(
+        (i128::from(local.year()), local.month(), local.day()),
+        (
+            local.hour(),
+            local.minute(),
+            local.second(),
+            local.nanosecond(),
+        ),
+        value.offset().fix().local_minus_utc(),
+    )
+    .unwrap_or_else(|| {
+        format_rfc3339(
+            i128::from(value.timestamp()),
+            value.timestamp_subsec_nanos(),
+        )
+    })
+}
+
+impl ParseFragment for NaiveDate {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        parse_date(value.trim())
+            .and_then(naive_date)
+            .ok_or(Error(value, Reason::Parse))
+    }
+}
+
+impl ToEString for NaiveDate {
+    fn to_estring(&self) -> EString {
+        EString(format_date(
+            i128::from(self.year()),
+            self.month(),
+            self.day(),
+        ))
+    }
+}
+
+impl ParseFragment for NaiveTime {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        parse_time(value.trim())
+            .and_then(naive_time)
+            .ok_or(Error(value, Reason::Parse))
+    }
+}
+
+impl ToEString for NaiveTime {
+    fn to_estring(&self) -> EString {
+        EString(format_time(
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.nanosecond(),
+        ))
+    }
+}
+
+impl ParseFragment for NaiveDateTime {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        parse_date_time(value.trim())
+            .and_then(|(date, time)| naive_date(date).zip(naive_time(time)))
+            .map(|(date, time)| date.and_time(time))
+            .ok_or(Error(value, Reason::Parse))
+    }
+}
+
+impl ToEString for NaiveDateTime {
+    fn to_estring(&self) -> EString {
+        EString(format!(
+            "{}T{}",
+            self.date().to_estring(),
+            self.time().to_estring()
+        ))
+    }
+}
+
+impl TimeFormat<DateTime<FixedOffset>> for Rfc3339 {
+    fn parse(s: &str) -> Option<DateTime<FixedOffset>> {
+        let (date, time, offset) = parse_rfc3339_local(s)?;
+        let date_time = naive_date(date)?.and_time(naive_time(time)?);
+        FixedOffset::east_opt(offset)?
+            .from_local_datetime(&date_time)
+            .single()
+    }
+
+    fn format(value: &DateTime<FixedOffset>) -> String {
+        format_rfc3339_with_offset(value)
+    }
+}
+
+impl TimeFormat<DateTime<Utc>> for Rfc3339 {
+    fn parse(s: &str) -> Option<DateTime<Utc>> {
+        <Self as TimeFormat<DateTime<FixedOffset>>>::parse(s).map(|res| res.with_timezone(&Utc))
+    }
+
+    fn format(value: &DateTime<Utc>) -> String {
+        format_rfc3339_with_offset(value)
+    }
+}
+
+impl TimeFormat<DateTime<Utc>> for UnixTimestamp {
+    fn parse(s: &str) -> Option<DateTime<Utc>> {
+        let (secs, nanos) = parse_unix_timestamp(s)?;
+        DateTime::from_timestamp(i64::try_from(secs).ok()?, nanos)
+    }
+
+    fn format(value: &DateTime<Utc>) -> String {
+        format_unix_timestamp(
+            i128::from(value.timestamp()),
+            value.timestamp_subsec_nanos(),
+        )
+    }
+}
+
+#[doc(hidden)]
+macro_rules! date_time_impl {
+    ($($ty:ty),+$(,)?) => {
+        $(
+            impl ParseFragment for $ty {
+                fn parse_frag(value: EString) -> crate::Result<Self> {
+                    match <Rfc3339 as TimeFormat<$ty>>::parse(value.trim()) {
+                        Some(res) => Ok(res),
+                        None => Err(Error(value, Reason::Parse)),
+                    }
+                }
+            }
+
+            impl ToEString for $ty {
+                fn to_estring(&self) -> EString {
+                    EString(<Rfc3339 as TimeFormat<$ty>>::format(self))
+                }
+            }
+        )+
+    };
+}
+
+date_time_impl![DateTime<FixedOffset>, DateTime<Utc>];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::time::Formatted;
+
+    #[test]
+    fn should_parse_naive_date() {
+        let estr = EString::from("2024-02-29");
+        match estr.parse::<NaiveDate>() {
+            Ok(res) => assert_eq!(res, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()),
+            _ => unreachable!(),
+        }
+
+        let estr = EString::from("2023-02-29");
+        match estr.parse::<NaiveDate>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("2023-02-29"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_naive_time() {
+        let test_cases = [
+            ("03:04", NaiveTime::from_hms_opt(3, 4, 0).unwrap()),
+            ("03:04:05", NaiveTime::from_hms_opt(3, 4, 5).unwrap()),
+            (
+                "03:04:05.25",
+                NaiveTime::from_hms_milli_opt(3, 4, 5, 250).unwrap(),
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<NaiveTime>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_naive_date_time() {
+        let expected = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .and_then(|date| date.and_hms_opt(3, 4, 5))
+            .unwrap();
+
+        for input in ["2024-01-02T03:04:05", "2024-01-02 03:04:05"] {
+            let estr = EString::from(input);
+            match estr.parse::<NaiveDateTime>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_date_time() {
+        let estr = EString::from("2024-01-02T05:04:05+02:00");
+        match estr.parse::<DateTime<Utc>>() {
+            Ok(res) => assert_eq!(res.timestamp(), 1_704_164_645),
+            _ => unreachable!(),
+        }
+
+        let estr = EString::from("2024-01-02T05:04:05+02:00");
+        match estr.parse::<DateTime<FixedOffset>>() {
+            Ok(res) => assert_eq!(res.offset().local_minus_utc(), 7_200),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_unix_timestamp() {
+        let estr = EString::from("1704164645.5");
+        match estr.parse::<Formatted<DateTime<Utc>, UnixTimestamp>>() {
+            Ok(res) => {
+                assert_eq!(res.timestamp(), 1_704_164_645);
+                assert_eq!(res.timestamp_subsec_millis(), 500);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_dates_at_range_edges() {
+        let test_cases = [
+            (NaiveDate::MIN, "-262143-01-01"),
+            (NaiveDate::MAX, "+262142-12-31"),
+            (NaiveDate::from_ymd_opt(-1, 1, 1).unwrap(), "-0001-01-01"),
+            (
+                NaiveDate::from_ymd_opt(10_000, 1, 1).unwrap(),
+                "+10000-01-01",
+            ),
+        ];
+
+        for (date, expected) in test_cases {
+            let estr = date.to_estring();
+            assert_eq!(estr, EString::from(expected));
+            assert_eq!(estr.parse::<NaiveDate>(), Ok(date));
+        }
+
+        let date_time = NaiveDate::from_ymd_opt(-1, 1, 1)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .unwrap()
+            .and_utc();
+        let estr = date_time.to_estring();
+        assert_eq!(estr, EString::from("-0001-01-01T00:00:00Z"));
+        assert_eq!(estr.parse::<DateTime<Utc>>(), Ok(date_time));
+    }
+
+    #[test]
+    fn should_format_leap_second() {
+        let time = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+        let estr = time.to_estring();
+        assert_eq!(estr, EString::from("23:59:60.5"));
+        assert_eq!(estr.parse::<NaiveTime>(), Ok(time));
+    }
+
+    #[test]
+    fn should_format_chrono_types() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        let time = NaiveTime::from_hms_milli_opt(3, 4, 5, 120).unwrap();
+        let date_time = date.and_time(time);
+
+        assert_eq!(date.to_estring(), EString::from("2024-01-02"));
+        assert_eq!(time.to_estring(), EString::from("03:04:05.12"));
+        assert_eq!(
+            date_time.to_estring(),
+            EString::from("2024-01-02T03:04:05.12")
+        );
+        assert_eq!(
+            date_time.and_utc().to_estring(),
+            EString::from("2024-01-02T03:04:05.12Z")
+        );
+
+        let offset = FixedOffset::west_opt(5_400).unwrap();
+        let date_time_with_offset = offset.from_local_datetime(&date_time).unwrap();
+        let estr = date_time_with_offset.to_estring();
+        assert_eq!(estr, EString::from("2024-01-02T03:04:05.12-01:30"));
+        assert_eq!(
+            estr.parse::<DateTime<FixedOffset>>(),
+            Ok(date_time_with_offset)
+        );
+
+        // RFC 3339 doesn't support offsets with seconds
+        let offset = FixedOffset::east_opt(3_723).unwrap();
+        let date_time_with_offset = offset.from_local_datetime(&date_time).unwrap();
+        let estr = date_time_with_offset.to_estring();
+        assert_eq!(estr, EString::from("2024-01-02T02:02:02.12Z"));
+        assert_eq!(
+            estr.parse::<DateTime<FixedOffset>>(),
+            Ok(date_time_with_offset)
+        );
+
+        let estr = date_time.to_estring();
+        assert_eq!(estr.parse::<NaiveDateTime>(), Ok(date_time));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+use crate::std::time::{
+    format_date, format_rfc3339, format_rfc3339_local, format_time, format_unix_timestamp,
+    parse_date, parse_date_time, parse_rfc3339_local, parse_time, parse_unix_timestamp, DateParts,
+    Rfc3339, TimeFormat, TimeParts, UnixTimestamp,
+};
+use ::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+fn date((year, month, day): DateParts) -> Option<Date> {
+    let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+    Date::from_calendar_date(i32::try_from(year).ok()?, month, u8::try_from(day).ok()?).ok()
+}
+
+fn time((hour, min, sec, nanos): TimeParts) -> Option<Time> {
+    Time::from_hms_nano(
+        u8::try_from(hour).ok()?,
+        u8::try_from(min).ok()?,
+        u8::try_from(sec).ok()?,
+        nanos,
+    )
+    .ok()
+}
+
+fn date_parts(date: Date) -> (i128, u32, u32) {
+    (
+        i128::from(date.year()),
+        u32::from(u8::from(date.month())),
+        u32::from(date.day()),
+    )
+}
+
+fn time_parts(time: Time) -> TimeParts {
+    (
+        u32::from(time.hour()),
+        u32::from(time.minute()),
+        u32::from(time.second()),
+        time.nanosecond(),
+    )
+}
+
+impl ParseFragment for Date {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        parse_date(value.trim())
+            .and_then(date)
+            .ok_or(Error(value, Reason::Parse))
+    }
+}
+
+impl ToEString for Date {
+    fn to_estring(&self) -> EString {
+        let (year, month, day) = date_parts(*self);
+        EString(format_date(year, month, day))
+    }
+}
+
+impl ParseFragment for Time {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        parse_time(value.trim())
+            .and_then(time)
+            .ok_or(Error(value, Reason::Parse))
+    }
+}
+
+impl ToEString for Time {
+    fn to_estring(&self) -> EString {
+        let (hour, min, sec, nanos) = time_parts(*self);
+        EString(format_time(hour, min, sec, nanos))
+    }
+}
+
+impl ParseFragment for PrimitiveDateTime {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        parse_date_time(value.trim())
+            .and_then(|(d, t)| date(d).zip(time(t)))
+            .map(|(date, time)| PrimitiveDateTime::new(date, time))
+            .ok_or(Error(value, Reason::Parse))
+    }
+}
+
+impl ToEString for PrimitiveDateTime {
+    fn to_estring(&self) -> EString {
+        EString(format!(
+            "{}T{}",
+            self.date().to_estring(),
+            self.time().to_estring()
+        ))
+    }
+}
+
+impl TimeFormat<OffsetDateTime> for Rfc3339 {
+    fn parse(s: &str) -> Option<OffsetDateTime> {
+        let (d, t, offset) = parse_rfc3339_local(s)?;
+        let offset = UtcOffset::from_whole_seconds(offset).ok()?;
+        Some(PrimitiveDateTime::new(date(d)?, time(t)?).assume_offset(offset))
+    }
+
+    // formats in UTC if the offset has seconds
+    fn format(value: &OffsetDateTime) -> String {
+        format_rfc3339_local(
+            date_parts(value.date()),
+            time_parts(value.time()),
+            value.offset().whole_seconds(),
+        )
+        .unwrap_or_else(|| format_rfc3339(i128::from(value.unix_timestamp()), value.nanosecond()))
+    }
+}
+
+impl TimeFormat<OffsetDateTime> for UnixTimestamp {
+    fn parse(s: &str) -> Option<OffsetDateTime> {
+        let (secs, nanos) = parse_unix_timestamp(s)?;
+        let nanos = secs.checked_mul(1_000_000_000)? + i128::from(nanos);
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+    }
+
+    fn format(value: &OffsetDateTime) -> String {
+        format_unix_timestamp(i128::from(value.unix_timestamp()), value.nanosecond())
+    }
+}
+
+impl ParseFragment for OffsetDateTime {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match <Rfc3339 as TimeFormat<Self>>::parse(value.trim()) {
+            Some(res) => Ok(res),
+            None => Err(Error(value, Reason::Parse)),
+        }
+    }
+}
+
+impl ToEString for OffsetDateTime {
+    fn to_estring(&self) -> EString {
+        EString(<Rfc3339 as TimeFormat<Self>>::format(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::time::Formatted;
+
+    fn date() -> Date {
+        Date::from_calendar_date(2024, Month::January, 2).unwrap()
+    }
+
+    #[test]
+    fn should_parse_date() {
+        let estr = EString::from("2024-01-02");
+        match estr.parse::<Date>() {
+            Ok(res) => assert_eq!(res, date()),
+            _ => unreachable!(),
+        }
+
+        let estr = EString::from("2024-01-32");
+        match estr.parse::<Date>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("2024-01-32"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_time() {
+        let test_cases = [
+            ("03:04", Time::from_hms(3, 4, 0).unwrap()),
+            ("03:04:05", Time::from_hms(3, 4, 5).unwrap()),
+            ("03:04:05.25", Time::from_hms_milli(3, 4, 5, 250).unwrap()),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Time>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_primitive_date_time() {
+        let expected = PrimitiveDateTime::new(date(), Time::from_hms(3, 4, 5).unwrap());
+
+        for input in ["2024-01-02T03:04:05", "2024-01-02 03:04:05"] {
+            let estr = EString::from(input);
+            match estr.parse::<PrimitiveDateTime>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_offset_date_time() {
+        let estr = EString::from("2024-01-02T05:04:05+02:00");
+        match estr.parse::<OffsetDateTime>() {
+            Ok(res) => {
+                assert_eq!(res.unix_timestamp(), 1_704_164_645);
+                assert_eq!(res.offset().whole_hours(), 2);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_unix_timestamp() {
+        let estr = EString::from("-1.5");
+        match estr.parse::<Formatted<OffsetDateTime, UnixTimestamp>>() {
+            Ok(res) => assert_eq!(res.unix_timestamp_nanos(), -1_500_000_000),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_dates_at_range_edges() {
+        let test_cases = [
+            (Date::MIN, "-9999-01-01"),
+            (Date::MAX, "9999-12-31"),
+            (Date::from_ordinal_date(-1, 1).unwrap(), "-0001-01-01"),
+        ];
+
+        for (date, expected) in test_cases {
+            let estr = date.to_estring();
+            assert_eq!(estr, EString::from(expected));
+            assert_eq!(estr.parse::<Date>(), Ok(date));
+        }
+    }
+
+    #[test]
+    fn should_format_offset_date_time() {
+        let test_cases = [
+            (
+                OffsetDateTime::from_unix_timestamp(-62_200_000_000).unwrap(),
+                "-0002-12-17T14:13:20Z",
+            ),
+            (
+                PrimitiveDateTime::new(date(), Time::MIDNIGHT)
+                    .assume_offset(UtcOffset::from_hms(-1, -30, 0).unwrap()),
+                "2024-01-02T00:00:00-01:30",
+            ),
+            (
+                PrimitiveDateTime::new(date(), Time::MIDNIGHT)
+                    .assume_offset(UtcOffset::from_hms(1, 2, 3).unwrap()),
+                "2024-01-01T22:57:57Z",
+            ),
+        ];
+
+        for (value, expected) in test_cases {
+            let estr = value.to_estring();
+            assert_eq!(estr, EString::from(expected));
+            assert_eq!(estr.parse::<OffsetDateTime>(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn should_format_time_types() {
+        let time = Time::from_hms_milli(3, 4, 5, 120).unwrap();
+        let date_time = PrimitiveDateTime::new(date(), time);
+
+        assert_eq!(date().to_estring(), EString::from("2024-01-02"));
+        assert_eq!(time.to_estring(), EString::from("03:04:05.12"));
+        assert_eq!(
+            date_time.to_estring(),
+            EString::from("2024-01-02T03:04:05.12")
+        );
+        assert_eq!(
+            date_time.assume_utc().to_estring(),
+            EString::from("2024-01-02T03:04:05.12Z")
+        );
+
+        let estr = date_time.to_estring();
+        assert_eq!(estr.parse::<PrimitiveDateTime>(), Ok(date_time));
+    }
+}
@@ -26,11 +26,17 @@
 //! Note: You can use custom types as annotations! Just implement ``ParseFragment``!
 //!
 //! ```rust
+//! # #[cfg(feature = "structs")]
 //! use estring::{SepVec, EString};
 //!
+//! # #[cfg(feature = "structs")]
 //! type PlusVec<T> = SepVec<T, '+'>;
+//! # #[cfg(feature = "structs")]
 //! type MulVec<T> = SepVec<T, '*'>;
 //!
+//! # #[cfg(not(feature = "structs"))]
+//! # fn main() {}
+//! # #[cfg(feature = "structs")]
 //! fn main() -> estring::Result<()> {
 //!     let res = EString::from("10+5*2+3")
 //!         .parse::<PlusVec<MulVec<f32>>>()?
@@ -46,11 +52,17 @@
 //! You can also use predefined aggregators if you enable `aggs` feature.
 //!
 //! ```rust
+//! # #[cfg(all(feature = "structs", feature = "aggs"))]
 //! use estring::{Aggregate, EString, Product, SepVec, Sum};
 //!
+//! # #[cfg(all(feature = "structs", feature = "aggs"))]
 //! type PlusVec<T> = SepVec<T, '+'>;
+//! # #[cfg(all(feature = "structs", feature = "aggs"))]
 //! type MulVec<T> = SepVec<T, '*'>;
 //!
+//! # #[cfg(not(all(feature = "structs", feature = "aggs")))]
+//! # fn main() {}
+//! # #[cfg(all(feature = "structs", feature = "aggs"))]
 //! fn main() -> estring::Result<()> {
 //!     let res = EString::from("10+5*2+3")
 //!         .parse::<Sum<PlusVec<Product<MulVec<f32>>>>>()?
@@ -127,4 +139,8 @@ pub mod structs;
 #[cfg(feature = "structs")]
 pub use structs::*;
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod ext;
+
 pub use crate::core::*;
+pub use crate::std::*;
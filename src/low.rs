@@ -3,5 +3,10 @@
 //! **NOTE**: Require the enabling the `low-level` feature.
 //!
 
+mod int;
+mod quoted;
 mod trim;
+
+pub use int::*;
+pub use quoted::*;
 pub use trim::*;
@@ -0,0 +1,458 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+
+/// Wrapper that allow to parse integers written in the extended notation
+///
+/// In addition to the plain notation the integer can contain:
+///
+/// * a radix prefix after an optional sign: `0x` (hex), `0o` (octal) or `0b` (binary);
+/// * underscores between digits, e.g. `1_000_000` or `0xFF_FF`;
+/// * commas as thousands separators of decimal integers, e.g. `1,000` or `12,345,678`;
+/// * an exponent for decimal integers, if the result is an integer, e.g. `1e6` or `2.5e3`.
+///
+/// Returns ``Reason::Parse`` if the substring is not an integer or is negative for an unsigned
+/// `T`, and ``Reason::Overflow`` if the integer doesn't fit into `T`.
+///
+/// The integer is formatted in the radix (`RADIX`) with the prefix. Radixes other than 16, 8 and
+/// 2 are formatted as decimal integers.
+///
+/// **NOTE**: Required the enabling of the `low-level` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Hex, Int, ToEString};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("0o755").parse::<Int<u32>>()?;
+///     assert_eq!(*res, 493);
+///     assert_eq!(res.to_estring(), EString::from("493"));
+///
+///     let res = EString::from("1_000e3").parse::<Int<u32>>()?;
+///     assert_eq!(*res, 1_000_000);
+///
+///     let res = EString::from("255").parse::<Hex<u8>>()?;
+///     assert_eq!(res.to_estring(), EString::from("0xff"));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Int<T, const RADIX: u32 = 10>(pub T);
+
+/// Wrapper for integers in the extended notation that is formatted as a hex integer.
+///
+/// **NOTE**: Required the enabling of the `low-level` feature.
+pub type Hex<T> = Int<T, 16>;
+
+/// Wrapper for integers in the extended notation that is formatted as an octal integer.
+///
+/// **NOTE**: Required the enabling of the `low-level` feature.
+pub type Oct<T> = Int<T, 8>;
+
+/// Wrapper for integers in the extended notation that is formatted as a binary integer.
+///
+/// **NOTE**: Required the enabling of the `low-level` feature.
+pub type Bin<T> = Int<T, 2>;
+
+/// Trait to convert primitive integers from and into a sign and a magnitude.
+///
+/// **NOTE**: Required the enabling of the `low-level` feature.
+pub trait Integer: Sized {
+    /// Whether the type can represent negative integers.
+    const SIGNED: bool;
+
+    /// Creates an integer from the sign and the magnitude, or returns `None` if it doesn't fit
+    /// into the type.
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self>;
+
+    /// Returns the sign and the magnitude of the integer.
+    fn to_sign_magnitude(&self) -> (bool, u128);
+}
+
+#[doc(hidden)]
+macro_rules! integer_impl {
+    (signed: $($ty:ty),+) => {
+        $(
+            impl Integer for $ty {
+                const SIGNED: bool = true;
+
+                fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                    let value = match i128::try_from(magnitude) {
+                        Ok(value) if negative => -value,
+                        Ok(value) => value,
+                        Err(_) if negative && magnitude == i128::MIN.unsigned_abs() => i128::MIN,
+                        Err(_) => return None,
+                    };
+                    Self::try_from(value).ok()
+                }
+
+                fn to_sign_magnitude(&self) -> (bool, u128) {
+                    let value = i128::try_from(*self).unwrap_or_default();
+                    (value < 0, value.unsigned_abs())
+                }
+            }
+        )+
+    };
+    (unsigned: $($ty:ty),+) => {
+        $(
+            impl Integer for $ty {
+                const SIGNED: bool = false;
+
+                fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                    if negative && magnitude != 0 {
+                        None
+                    } else {
+                        Self::try_from(magnitude).ok()
+                    }
+                }
+
+                fn to_sign_magnitude(&self) -> (bool, u128) {
+                    (false, u128::try_from(*self).unwrap_or_default())
+                }
+            }
+        )+
+    };
+}
+
+integer_impl!(signed: i8, i16, i32, i64, i128, isize);
+integer_impl!(unsigned: u8, u16, u32, u64, u128, usize);
+
+impl<T, const RADIX: u32> std::ops::Deref for Int<T, RADIX> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Integer, const RADIX: u32> std::fmt::Display for Int<T, RADIX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (negative, magnitude) = self.0.to_sign_magnitude();
+        if negative {
+            f.write_str("-")?;
+        }
+        match RADIX {
+            16 => write!(f, "0x{magnitude:x}"),
+            8 => write!(f, "0o{magnitude:o}"),
+            2 => write!(f, "0b{magnitude:b}"),
+            _ => write!(f, "{magnitude}"),
+        }
+    }
+}
+
+impl<T, const RADIX: u32> ParseFragment for Int<T, RADIX>
+where
+    T: Integer,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match parse_int(value.trim()) {
+            Ok((true, magnitude)) if !T::SIGNED && magnitude != 0 => {
+                Err(Error(value, Reason::Parse))
+            }
+            Ok((negative, magnitude)) => T::from_sign_magnitude(negative, magnitude)
+                .map(Int)
+                .ok_or(Error(value, Reason::Overflow)),
+            Err(reason) => Err(Error(value, reason)),
+        }
+    }
+}
+
+impl<T, const RADIX: u32> ToEString for Int<T, RADIX>
+where
+    T: Integer,
+{
+    fn to_estring(&self) -> EString {
+        EString(self.to_string())
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const RADIX: u32> crate::core::Aggregatable for Int<T, RADIX> {
+    type Item = T;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        vec![self.0]
+    }
+}
+
+/// Parses the sign and the magnitude of an integer.
+fn parse_int(s: &str) -> Result<(bool, u128), Reason> {
+    let (negative, s) = split_sign(s);
+    let radix = match s.get(..2).map(str::to_ascii_lowercase).as_deref() {
+        Some("0x") => 16,
+        Some("0o") => 8,
+        Some("0b") => 2,
+        _ => return parse_decimal(s).map(|magnitude| (negative, magnitude)),
+    };
+
+    let digits = &s[2..];
+    if !is_digits(digits, radix, &['_'][..]) {
+        return Err(Reason::Parse);
+    }
+    digits_value(digits, radix)
+        .map(|magnitude| (negative, magnitude))
+        .ok_or(Reason::Overflow)
+}
+
+/// Parses a decimal magnitude with an optional fraction and exponent.
+fn parse_decimal(s: &str) -> Result<u128, Reason> {
+    let (mantissa, exp) = match s.split_once(&['e', 'E'][..]) {
+        Some((mantissa, exp)) => (mantissa, Some(exp)),
+        None => (s, None),
+    };
+    let (int, frac) = match mantissa.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (mantissa, None),
+    };
+    let (exp_negative, exp) = match exp {
+        Some(exp) => split_sign(exp),
+        None if frac.is_some() => return Err(Reason::Parse),
+        None => (false, "0"),
+    };
+
+    let is_decimal = (is_digits(int, 10, &['_'][..]) || is_grouped_digits(int))
+        && frac.map_or(true, |frac| is_digits(frac, 10, &['_'][..]))
+        && is_digits(exp, 10, &['_'][..]);
+    if !is_decimal {
+        return Err(Reason::Parse);
+    }
+
+    let scale = frac.map_or(0, |frac| frac.chars().filter(char::is_ascii_digit).count());
+    let scale = i128::try_from(scale).map_err(|_| Reason::Overflow)?;
+    let digits = digits_value(int, 10)
+        .zip(frac.map_or(Some(0), |frac| digits_value(frac, 10)))
+        .and_then(|(int, frac)| int.checked_mul(pow10(scale)?)?.checked_add(frac))
+        .ok_or(Reason::Overflow)?;
+    if digits == 0 {
+        return Ok(0);
+    }
+
+    let exp = digits_value(exp, 10)
+        .and_then(|exp| i128::try_from(exp).ok())
+        .map(|exp| if exp_negative { -exp } else { exp } - scale);
+    match exp {
+        Some(exp) if exp >= 0 => pow10(exp)
+            .and_then(|p| digits.checked_mul(p))
+            .ok_or(Reason::Overflow),
+        // the result is an integer only if the digits are divisible by the power of ten
+        Some(exp) => match pow10(-exp) {
+            Some(p) if digits % p == 0 => Ok(digits / p),
+            _ => Err(Reason::Parse),
+        },
+        None if exp_negative => Err(Reason::Parse),
+        None => Err(Reason::Overflow),
+    }
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    }
+}
+
+fn pow10(exp: i128) -> Option<u128> {
+    u32::try_from(exp)
+        .ok()
+        .and_then(|exp| 10u128.checked_pow(exp))
+}
+
+/// Checks that the substring contains only digits in the radix, and the separators (`seps`)
+/// are between them.
+fn is_digits(s: &str, radix: u32, seps: &[char]) -> bool {
+    let mut prev_sep = true;
+    for c in s.chars() {
+        if seps.contains(&c) {
+            if prev_sep {
+                return false;
+            }
+            prev_sep = true;
+        } else if c.is_digit(radix) {
+            prev_sep = false;
+        } else {
+            return false;
+        }
+    }
+    !prev_sep
+}
+
+/// Checks that the substring contains decimal digits grouped by thousands with commas, i.e.
+/// 1-3 leading digits and then groups of exactly 3 digits.
+fn is_grouped_digits(s: &str) -> bool {
+    let is_digits = |group: &str| group.bytes().all(|b| b.is_ascii_digit());
+    let mut groups = s.split(',');
+    match groups.next() {
+        Some(first) if (1..=3).contains(&first.len()) && is_digits(first) => {
+            groups.all(|group| group.len() == 3 && is_digits(group))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the value of digits in the radix skipping separators, or `None` on overflow.
+fn digits_value(s: &str, radix: u32) -> Option<u128> {
+    s.chars()
+        .filter_map(|c| c.to_digit(radix))
+        .try_fold(0u128, |res, digit| {
+            res.checked_mul(u128::from(radix))?
+                .checked_add(u128::from(digit))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_plain_integers() {
+        let test_cases = [("0", 0), ("42", 42), ("-42", -42), ("+42", 42), (" 7 ", 7)];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Int<i32>>() {
+                Ok(res) => assert_eq!(res, Int(expected)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_radix_prefixes() {
+        let test_cases = [
+            ("0xFF", 255),
+            ("0Xff", 255),
+            ("0o755", 493),
+            ("0b1010", 10),
+            ("-0x10", -16),
+            ("0xFF_FF", 65_535),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Int<i64>>() {
+                Ok(res) => assert_eq!(res, Int(expected)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_digit_separators() {
+        let test_cases = [
+            ("1_000_000", 1_000_000),
+            ("1,000", 1_000),
+            ("12,345,678", 12_345_678),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Int<u64>>() {
+                Ok(res) => assert_eq!(res, Int(expected)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_exponents() {
+        let test_cases = [
+            ("1e6", 1_000_000),
+            ("1E6", 1_000_000),
+            ("2.5e3", 2_500),
+            ("1.000e3", 1_000),
+            ("1000e-3", 1),
+            ("0e99", 0),
+            ("-1e2", -100),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Int<i64>>() {
+                Ok(res) => assert_eq!(res, Int(expected)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_min_and_max_integers() {
+        let estr = EString::from("-0x80");
+        assert_eq!(estr.parse::<Int<i8>>(), Ok(Int(i8::MIN)));
+
+        let estr = EString::from("-170141183460469231731687303715884105728");
+        assert_eq!(estr.parse::<Int<i128>>(), Ok(Int(i128::MIN)));
+
+        let estr = EString::from("0xffffffffffffffffffffffffffffffff");
+        assert_eq!(estr.parse::<Int<u128>>(), Ok(Int(u128::MAX)));
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        let test_cases = [
+            "", "-", "0x", "0xZZ", "1__000", "_1", "1_", "1,", "0x1,000", "1.5", "1.5e0", "15e-1",
+            "1e", "1e1.5", "0b102", "1 000", "1,2", "1,00", "1,0000", "1234,567", ",100", "1,,000",
+            "1_0,000",
+        ];
+
+        for input in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Int<i64>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Parse);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error_on_negative_unsigned() {
+        for input in ["-1", "-0x1", "-1e2"] {
+            let estr = EString::from(input);
+            match estr.parse::<Int<u8>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Parse);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        let estr = EString::from("-0");
+        assert_eq!(estr.parse::<Int<u8>>(), Ok(Int(0)));
+    }
+
+    #[test]
+    fn should_throw_overflow_error() {
+        let test_cases = ["256", "0x100", "1e3", "1e99", "1e999999999999999999999999"];
+
+        for input in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Int<u8>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Overflow);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_integer_in_radix() {
+        assert_eq!(Int::<_, 10>(-42).to_estring(), EString::from("-42"));
+        assert_eq!(Int::<_, 16>(255u8).to_estring(), EString::from("0xff"));
+        assert_eq!(Int::<_, 8>(493).to_estring(), EString::from("0o755"));
+        assert_eq!(Int::<_, 2>(-10).to_estring(), EString::from("-0b1010"));
+        assert_eq!(Int::<_, 16>(i8::MIN).to_estring(), EString::from("-0x80"));
+    }
+
+    #[test]
+    fn should_parse_formatted_integer_back() {
+        let num: Bin<i16> = Int(-10);
+        assert_eq!(num.to_estring().parse::<Bin<i16>>(), Ok(num));
+    }
+}
@@ -0,0 +1,208 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+
+/// Wrapper that allow to unquote substring and decode escapes before continue
+///
+/// The substring can be wrapped in double (`"..."`) or single (`'...'`) quotes. Inside quotes
+/// the escapes `\n`, `\r`, `\t`, `\0`, `\\`, `\"`, `\'` and `\u{..}` are decoded. Unquoted
+/// substring is passed as is.
+///
+/// Returns ``Reason::Split`` if the quote is not terminated, and ``Reason::Parse`` if the
+/// substring contains an unknown escape or characters after the closing quote.
+///
+/// The value is always formatted in double quotes with escapes.
+///
+/// **NOTE**: Required the enabling of the `low-level` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Quoted, ToEString};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from(r#""hello \"world\"\n""#).parse::<Quoted<String>>()?;
+///     assert_eq!(*res, "hello \"world\"\n");
+///     assert_eq!(res.to_estring(), EString::from(r#""hello \"world\"\n""#));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq)]
+pub struct Quoted<T>(pub T);
+
+impl<T> std::ops::Deref for Quoted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Quoted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&quote(&self.0.to_string()))
+    }
+}
+
+impl<T> ParseFragment for Quoted<T>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match value.chars().next() {
+            Some(q @ ('"' | '\'')) => {
+                let inner =
+                    unquote(&value[1..], q).map_err(|reason| Error(value.clone(), reason))?;
+                T::parse_frag(EString(inner)).map(Quoted)
+            }
+            _ => T::parse_frag(value).map(Quoted),
+        }
+    }
+}
+
+impl<T> ToEString for Quoted<T>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        EString(quote(&self.0.to_estring()))
+    }
+}
+
+/// Decodes the substring after the opening quote (`q`) up to the closing quote.
+fn unquote(value: &str, q: char) -> Result<String, Reason> {
+    let mut res = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    loop {
+        match chars.next().ok_or(Reason::Split)? {
+            c if c == q => break,
+            '\\' => res.push(unescape(&mut chars)?),
+            c => res.push(c),
+        }
+    }
+
+    if chars.next().is_some() {
+        Err(Reason::Parse)
+    } else {
+        Ok(res)
+    }
+}
+
+fn unescape(chars: &mut std::str::Chars<'_>) -> Result<char, Reason> {
+    match chars.next().ok_or(Reason::Split)? {
+        'n' => Ok('\n'),
+        'r' => Ok('\r'),
+        't' => Ok('\t'),
+        '0' => Ok('\0'),
+        c @ ('\\' | '"' | '\'') => Ok(c),
+        'u' => {
+            if chars.next() != Some('{') {
+                return Err(Reason::Parse);
+            }
+            let hex = chars.by_ref().take_while(|&c| c != '}').collect::<String>();
+            u32::from_str_radix(&hex, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or(Reason::Parse)
+        }
+        _ => Err(Reason::Parse),
+    }
+}
+
+fn quote(value: &str) -> String {
+    let mut res = String::with_capacity(value.len() + 2);
+    res.push('"');
+    for c in value.chars() {
+        match c {
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            '\0' => res.push_str("\\0"),
+            '\\' | '"' => {
+                res.push('\\');
+                res.push(c);
+            }
+            c if c.is_control() => res.extend(c.escape_unicode()),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_unquote_double_quoted_string() {
+        let estr = EString::from(r#""a \"b\"\t\\ \u{1F600}""#);
+        match estr.parse::<Quoted<String>>() {
+            Ok(res) => assert_eq!(*res, "a \"b\"\t\\ \u{1F600}"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_unquote_single_quoted_string() {
+        let estr = EString::from(r#"'a "b" \'c\''"#);
+        match estr.parse::<Quoted<String>>() {
+            Ok(res) => assert_eq!(*res, r#"a "b" 'c'"#),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_pass_unquoted_string() {
+        let estr = EString::from(r"a\n");
+        match estr.parse::<Quoted<&str>>() {
+            Ok(res) => assert_eq!(*res, r"a\n"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_unquote_and_convert_to_number() {
+        let estr = EString::from("'99'");
+        match estr.parse::<Quoted<i32>>() {
+            Ok(res) => assert_eq!(*res, 99),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error_on_unterminated_quote() {
+        for input in [r#""abc"#, r#""abc\""#] {
+            let estr = EString::from(input);
+            match estr.parse::<Quoted<String>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Split);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error_on_invalid_escape() {
+        for input in [r#""\x""#, r#""\u{zz}""#, r#""a"b"#] {
+            let estr = EString::from(input);
+            match estr.parse::<Quoted<String>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Parse);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_quoted_string() {
+        let quoted = Quoted(String::from("a \"b\"\n\\\u{7}"));
+        let estr = quoted.to_estring();
+        assert_eq!(estr, EString::from(r#""a \"b\"\n\\\u{7}""#));
+        assert_eq!(estr.parse::<Quoted<String>>(), Ok(quoted));
+    }
+}
@@ -1,6 +1,23 @@
-//! Contains implementations for standard types (`bool`, numbers, `Option`, etc.)
+//! Contains implementations for standard types (`bool`, numbers, `Option`, etc.) and the
+//! common value types (``Bool``, ``Ratio``, ``SearchPath``, etc.)
 //!
 
 mod bool;
-mod number;
+mod char;
+mod duration;
+mod net;
+mod nonzero;
+pub(crate) mod number;
 mod option;
+mod path;
+mod pointer;
+pub(crate) mod range;
+mod ratio;
+pub(crate) mod time;
+mod unit;
+mod wrapping;
+
+pub use self::bool::*;
+pub use path::*;
+pub use ratio::*;
+pub use time::{Formatted, Rfc3339, TimeFormat, UnixTimestamp};
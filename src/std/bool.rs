@@ -1,17 +1,224 @@
 use crate::core::{EString, ParseFragment, ToEString};
 use crate::error::{Error, Reason};
+use std::marker::PhantomData;
 
-impl ParseFragment for bool {
+/// Trait to represent the words of boolean values.
+///
+/// The first word of each list is used to format the value.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{Bool, BoolVocabulary, EString, ToEString};
+///
+/// struct German;
+///
+/// impl BoolVocabulary for German {
+///     const TRUE: &'static [&'static str] = &["ja", "wahr"];
+///     const FALSE: &'static [&'static str] = &["nein", "falsch"];
+/// }
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("Wahr").parse::<Bool<German>>()?;
+///     assert_eq!(*res, true);
+///     assert_eq!(res.to_estring(), EString::from("ja"));
+///     Ok(())
+/// }
+/// ```
+///
+pub trait BoolVocabulary {
+    /// Words of the `true` value.
+    const TRUE: &'static [&'static str];
+    /// Words of the `false` value.
+    const FALSE: &'static [&'static str];
+    /// Parse the empty string as `false` instead of returning ``Reason::Empty``.
+    const EMPTY_AS_FALSE: bool = false;
+    /// Compare words case-sensitively.
+    const CASE_SENSITIVE: bool = false;
+}
+
+/// Vocabulary of `true` and `false` words only.
+pub struct StrictBool;
+
+impl BoolVocabulary for StrictBool {
+    const TRUE: &'static [&'static str] = &["true"];
+    const FALSE: &'static [&'static str] = &["false"];
+}
+
+/// Vocabulary of the ``bool`` implementation: `true`, `t`, `yes`, `y`, `on`, `1` and `false`,
+/// `f`, `no`, `n`, `off`, `0`. The empty string is parsed as `false`.
+pub struct ExtendedBool;
+
+impl BoolVocabulary for ExtendedBool {
+    const TRUE: &'static [&'static str] = &["true", "t", "yes", "y", "on", "1"];
+    const FALSE: &'static [&'static str] = &["false", "f", "no", "n", "off", "0"];
+    const EMPTY_AS_FALSE: bool = true;
+}
+
+/// Vocabulary of `yes`, `y` and `no`, `n` words.
+pub struct YesNo;
+
+impl BoolVocabulary for YesNo {
+    const TRUE: &'static [&'static str] = &["yes", "y"];
+    const FALSE: &'static [&'static str] = &["no", "n"];
+}
+
+/// Vocabulary of `on` and `off` words.
+pub struct OnOff;
+
+impl BoolVocabulary for OnOff {
+    const TRUE: &'static [&'static str] = &["on"];
+    const FALSE: &'static [&'static str] = &["off"];
+}
+
+/// Vocabulary of `1` and `0`.
+pub struct OneZero;
+
+impl BoolVocabulary for OneZero {
+    const TRUE: &'static [&'static str] = &["1"];
+    const FALSE: &'static [&'static str] = &["0"];
+}
+
+/// Bool vocabulary that accepts the words of `V`, but rejects the empty string with
+/// ``Reason::Empty``.
+pub struct NonEmptyBool<V>(PhantomData<V>);
+
+impl<V: BoolVocabulary> BoolVocabulary for NonEmptyBool<V> {
+    const TRUE: &'static [&'static str] = V::TRUE;
+    const FALSE: &'static [&'static str] = V::FALSE;
+    const CASE_SENSITIVE: bool = V::CASE_SENSITIVE;
+}
+
+/// Wrapper for ``bool`` to parse words of a vocabulary (`V`), and format the value with the
+/// first words of a vocabulary (`S`).
+///
+/// Returns ``Reason::Parse`` if the word is unknown, and ``Reason::Empty`` if the string is
+/// empty and the vocabulary doesn't parse it as `false`.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{Bool, EString, ExtendedBool, NonEmptyBool, OnOff, StrictBool, ToEString};
+///
+/// fn main() -> estring::Result<()> {
+///     assert!(EString::from("yes").parse::<Bool<StrictBool>>().is_err());
+///     assert!(EString::new().parse::<Bool<NonEmptyBool<ExtendedBool>>>().is_err());
+///
+///     let res = EString::from("yes").parse::<Bool<ExtendedBool, OnOff>>()?;
+///     assert_eq!(res.to_estring(), EString::from("on"));
+///     Ok(())
+/// }
+/// ```
+///
+pub struct Bool<V = StrictBool, S = V>(pub bool, PhantomData<(V, S)>);
+
+impl<V, S> std::fmt::Debug for Bool<V, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Bool").field(&self.0).finish()
+    }
+}
+
+impl<V, S> PartialEq for Bool<V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<V, S> Eq for Bool<V, S> {}
+
+impl<V, S> Clone for Bool<V, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V, S> Copy for Bool<V, S> {}
+
+impl<V, S> std::ops::Deref for Bool<V, S> {
+    type Target = bool;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<V, S> From<bool> for Bool<V, S> {
     #[inline]
+    fn from(value: bool) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<V, S: BoolVocabulary> std::fmt::Display for Bool<V, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let words = if self.0 { S::TRUE } else { S::FALSE };
+        match words.first() {
+            Some(word) => f.write_str(word),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl<V, S: BoolVocabulary> ToEString for Bool<V, S> {
+    #[inline]
+    fn to_estring(&self) -> EString {
+        EString(self.to_string())
+    }
+}
+
+impl<V: BoolVocabulary, S> ParseFragment for Bool<V, S> {
     fn parse_frag(s: EString) -> crate::Result<Self> {
-        match s.to_lowercase().as_str() {
-            "true" | "t" | "yes" | "y" | "on" | "1" => Ok(true),
-            "false" | "f" | "no" | "n" | "off" | "0" | "" => Ok(false),
-            _ => Err(Error(s, Reason::Parse)),
+        if s.is_empty() {
+            return if V::EMPTY_AS_FALSE {
+                Ok(Self::from(false))
+            } else {
+                Err(Error(s, Reason::Empty))
+            };
+        }
+
+        let value = if V::CASE_SENSITIVE {
+            s.0.clone()
+        } else {
+            s.to_lowercase()
+        };
+        let matches = |words: &[&str]| {
+            words.iter().any(|word| {
+                if V::CASE_SENSITIVE {
+                    *word == value
+                } else {
+                    word.to_lowercase() == value
+                }
+            })
+        };
+
+        if matches(V::TRUE) {
+            Ok(Self::from(true))
+        } else if matches(V::FALSE) {
+            Ok(Self::from(false))
+        } else {
+            Err(Error(s, Reason::Parse))
         }
     }
 }
 
+#[cfg(feature = "aggs")]
+impl<V, S> crate::core::Aggregatable for Bool<V, S> {
+    type Item = bool;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        vec![self.0]
+    }
+}
+
+impl ParseFragment for bool {
+    #[inline]
+    fn parse_frag(s: EString) -> crate::Result<Self> {
+        Bool::<ExtendedBool>::parse_frag(s).map(|res| res.0)
+    }
+}
+
 impl ToEString for bool {
     #[inline]
     fn to_estring(&self) -> EString {
@@ -54,7 +261,7 @@ mod tests {
             match estr.parse::<bool>() {
                 Ok(res) => assert_eq!(res, expected),
                 _ => unreachable!(),
-            };
+            }
         }
     }
 
@@ -67,7 +274,7 @@ mod tests {
                 assert_eq!(reason, crate::Reason::Parse);
             }
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -75,4 +282,76 @@ mod tests {
         assert_eq!(true.to_estring(), EString(String::from("true")));
         assert_eq!(false.to_estring(), EString(String::from("false")));
     }
+
+    #[test]
+    fn should_parse_strict_bool() {
+        for (val, expected) in [("true", true), ("FALSE", false)] {
+            let estr = EString::from(val);
+            match estr.parse::<Bool<StrictBool>>() {
+                Ok(res) => assert_eq!(*res, expected),
+                _ => unreachable!(),
+            }
+        }
+
+        let estr = EString::from("yes");
+        match estr.parse::<Bool<StrictBool>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("yes"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_empty_error() {
+        match EString::new().parse::<Bool<NonEmptyBool<ExtendedBool>>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::new());
+                assert_eq!(reason, Reason::Empty);
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(
+            EString::new().parse::<Bool<ExtendedBool>>(),
+            Ok(Bool::from(false))
+        );
+    }
+
+    #[test]
+    fn should_compare_case_sensitively() {
+        struct Upper;
+
+        impl BoolVocabulary for Upper {
+            const TRUE: &'static [&'static str] = &["Y"];
+            const FALSE: &'static [&'static str] = &["N"];
+            const CASE_SENSITIVE: bool = true;
+        }
+
+        assert_eq!(
+            EString::from("Y").parse::<Bool<Upper>>(),
+            Ok(Bool::from(true))
+        );
+        assert!(EString::from("y").parse::<Bool<Upper>>().is_err());
+    }
+
+    #[test]
+    fn should_format_bool_with_spelling() {
+        assert_eq!(
+            Bool::<StrictBool>::from(true).to_estring(),
+            EString::from("true")
+        );
+        assert_eq!(
+            Bool::<ExtendedBool, YesNo>::from(true).to_estring(),
+            EString::from("yes")
+        );
+        assert_eq!(
+            Bool::<YesNo, OnOff>::from(false).to_estring(),
+            EString::from("off")
+        );
+        assert_eq!(
+            Bool::<OneZero>::from(false).to_estring(),
+            EString::from("0")
+        );
+    }
 }
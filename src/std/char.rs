@@ -0,0 +1,55 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+
+impl ParseFragment for char {
+    #[inline]
+    fn parse_frag(s: EString) -> crate::Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Error(s, Reason::Char)),
+        }
+    }
+}
+
+impl ToEString for char {
+    #[inline]
+    fn to_estring(&self) -> EString {
+        EString(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_char() {
+        for (input, expected) in [("a", 'a'), ("ß", 'ß'), (" ", ' ')] {
+            let estr = EString::from(input);
+            match estr.parse::<char>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_char_error() {
+        for input in ["", "ab"] {
+            let estr = EString::from(input);
+            match estr.parse::<char>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Char);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_char() {
+        assert_eq!('x'.to_estring(), EString::from("x"));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+use crate::std::number::{scale_decimal, split_decimal};
+use std::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Units in descending order that are used to format and parse a duration.
+const FORMAT_UNITS: [(&str, u128); 6] = [
+    ("h", 3_600 * NANOS_PER_SEC),
+    ("m", 60 * NANOS_PER_SEC),
+    ("s", NANOS_PER_SEC),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// Units that are only used to parse a duration.
+const PARSE_ONLY_UNITS: [(&str, u128); 2] = [("d", 86_400 * NANOS_PER_SEC), ("µs", 1_000)];
+
+/// Parses a single component like `1.5h` and returns the number of nanoseconds and the rest of
+/// the string.
+fn parse_component(s: &str) -> Option<(Option<u128>, &str)> {
+    let (int, frac, rest) = split_decimal(s)?;
+    let unit_end = rest
+        .find(|c: char| !(c.is_alphabetic()))
+        .unwrap_or(rest.len());
+    let (unit, rest) = rest.split_at(unit_end);
+
+    let unit = FORMAT_UNITS
+        .iter()
+        .chain(&PARSE_ONLY_UNITS)
+        .find(|(u, _)| *u == unit)
+        .map(|(_, n)| *n)?;
+    Some((scale_decimal(int, frac, unit), rest.trim_start()))
+}
+
+impl ParseFragment for Duration {
+    fn parse_frag(s: EString) -> crate::Result<Self> {
+        let mut rest = s.trim();
+        if rest == "0" {
+            return Ok(Duration::ZERO);
+        }
+        if rest.is_empty() {
+            return Err(Error(s, Reason::Parse));
+        }
+
+        let mut total = Some(0u128);
+        while !rest.is_empty() {
+            let (nanos, tail) = match parse_component(rest) {
+                Some(res) => res,
+                None => return Err(Error(s, Reason::Parse)),
+            };
+            total = total.zip(nanos).and_then(|(t, n)| t.checked_add(n));
+            rest = tail;
+        }
+
+        total
+            .and_then(|total| {
+                let secs = u64::try_from(total / NANOS_PER_SEC).ok()?;
+                let nanos = u32::try_from(total % NANOS_PER_SEC).ok()?;
+                Some(Duration::new(secs, nanos))
+            })
+            .ok_or(Error(s, Reason::Overflow))
+    }
+}
+
+impl ToEString for Duration {
+    fn to_estring(&self) -> EString {
+        if self.is_zero() {
+            return EString::from("0s");
+        }
+
+        let mut rest = self.as_nanos();
+        let mut res = String::new();
+        for (unit, nanos) in &FORMAT_UNITS {
+            let value = rest / nanos;
+            if value > 0 {
+                res.push_str(&value.to_string());
+                res.push_str(unit);
+                rest %= nanos;
+            }
+        }
+        EString(res)
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl crate::core::Aggregatable for Duration {
+    type Item = Self;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        vec![self]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_duration_with_unit() {
+        let test_cases = [
+            ("30s", Duration::from_secs(30)),
+            ("250ms", Duration::from_millis(250)),
+            ("2h", Duration::from_secs(7_200)),
+            ("1d", Duration::from_secs(86_400)),
+            ("10us", Duration::from_micros(10)),
+            ("10µs", Duration::from_micros(10)),
+            ("7ns", Duration::from_nanos(7)),
+            ("0", Duration::ZERO),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Duration>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_compound_duration() {
+        let estr = EString::from("1h 2m30s 5ms");
+        match estr.parse::<Duration>() {
+            Ok(res) => assert_eq!(res, Duration::from_millis(3_750_005)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_fractional_duration() {
+        let test_cases = [
+            ("1.5h", Duration::from_secs(5_400)),
+            ("0.25s", Duration::from_millis(250)),
+            (".5m", Duration::from_secs(30)),
+            ("1.0000000001s", Duration::from_secs(1)),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Duration>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        for input in ["", "30", "s", "-1s", "1x", "1.2.3s", "1h30"] {
+            let estr = EString::from(input);
+            match estr.parse::<Duration>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Parse);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_overflow_error() {
+        for input in [
+            "18446744073709551616s",
+            "999999999999999999999999999999999999999h",
+        ] {
+            let estr = EString::from(input);
+            match estr.parse::<Duration>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Overflow);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_duration() {
+        let test_cases = [
+            (Duration::ZERO, "0s"),
+            (Duration::from_secs(90), "1m30s"),
+            (Duration::from_millis(250), "250ms"),
+            (Duration::from_secs(90_000), "25h"),
+            (Duration::new(3_601, 1_001), "1h1s1us1ns"),
+        ];
+
+        for (duration, expected) in test_cases {
+            let estr = duration.to_estring();
+            assert_eq!(estr, EString::from(expected));
+            assert_eq!(estr.parse::<Duration>(), Ok(duration));
+        }
+    }
+}
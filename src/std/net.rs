@@ -0,0 +1,84 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+#[doc(hidden)]
+macro_rules! from_env_string_net_impl {
+    ($($ty:ty),+$(,)?) => {
+        $(
+            impl ParseFragment for $ty {
+                #[inline]
+                fn parse_frag(s: EString) -> crate::Result<Self> {
+                    s.0.parse::<Self>().map_err(|_| Error(s, Reason::Parse))
+                }
+            }
+
+            impl ToEString for $ty {
+                #[inline]
+                fn to_estring(&self) -> EString {
+                    EString(self.to_string())
+                }
+            }
+        )+
+    };
+}
+
+#[rustfmt::skip]
+from_env_string_net_impl![
+    IpAddr, Ipv4Addr, Ipv6Addr,
+    SocketAddr, SocketAddrV4, SocketAddrV6
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_ip_addresses() {
+        let estr = EString::from("10.0.0.1");
+        match estr.parse::<IpAddr>() {
+            Ok(res) => assert_eq!(res, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))),
+            _ => unreachable!(),
+        }
+
+        let estr = EString::from("::1");
+        match estr.parse::<Ipv6Addr>() {
+            Ok(res) => assert_eq!(res, Ipv6Addr::LOCALHOST),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_socket_addresses() {
+        let estr = EString::from("10.0.0.1:8080");
+        match estr.parse::<SocketAddr>() {
+            Ok(res) => assert_eq!(res, SocketAddr::from(([10, 0, 0, 1], 8080))),
+            _ => unreachable!(),
+        }
+
+        let estr = EString::from("[::1]:443");
+        match estr.parse::<SocketAddrV6>() {
+            Ok(res) => assert_eq!(res, SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        let estr = EString::from("10.0.0.256");
+        match estr.parse::<Ipv4Addr>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("10.0.0.256"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_addresses() {
+        let addr = SocketAddr::from((Ipv6Addr::LOCALHOST, 443));
+        assert_eq!(addr.to_estring(), EString::from("[::1]:443"));
+        assert_eq!(Ipv4Addr::LOCALHOST.to_estring(), EString::from("127.0.0.1"));
+    }
+}
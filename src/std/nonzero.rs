@@ -0,0 +1,99 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+#[doc(hidden)]
+macro_rules! from_env_string_nonzero_impl {
+    ($($ty:ty => $inner:ty),+$(,)?) => {
+        $(
+            impl ParseFragment for $ty {
+                #[inline]
+                fn parse_frag(s: EString) -> crate::Result<Self> {
+                    let inner = s.0.parse::<$inner>().map_err(|_| Error(s.clone(), Reason::Parse))?;
+                    Self::new(inner).ok_or(Error(s, Reason::Zero))
+                }
+            }
+
+            impl ToEString for $ty {
+                #[inline]
+                fn to_estring(&self) -> EString {
+                    EString(self.to_string())
+                }
+            }
+
+            #[cfg(feature = "aggs")]
+            impl crate::core::Aggregatable for $ty {
+                type Item = $inner;
+
+                #[inline]
+                fn items(self) -> Vec<Self::Item> {
+                    vec![self.get()]
+                }
+            }
+        )+
+    };
+}
+
+#[rustfmt::skip]
+from_env_string_nonzero_impl![
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32,
+    NonZeroI64 => i64, NonZeroI128 => i128, NonZeroIsize => isize,
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32,
+    NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_nonzero_number() {
+        let estr = EString::from("-10");
+        match estr.parse::<NonZeroI32>() {
+            Ok(res) => assert_eq!(res.get(), -10),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_zero_error() {
+        let estr = EString::from("0");
+        match estr.parse::<NonZeroU16>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("0"));
+                assert_eq!(reason, Reason::Zero);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        let estr = EString::from("-1");
+        match estr.parse::<NonZeroU16>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("-1"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_nonzero_number() {
+        let num = NonZeroU8::new(42).unwrap();
+        assert_eq!(num.to_estring(), EString::from("42"));
+    }
+
+    #[cfg(feature = "aggs")]
+    #[test]
+    fn should_return_inner_number_as_item() {
+        use crate::core::Aggregatable;
+
+        let num = NonZeroU8::new(42).unwrap();
+        assert_eq!(num.items(), vec![42u8]);
+    }
+}
@@ -39,6 +39,45 @@ from_env_string_numbers_impl![
     f32, f64
 ];
 
+/// Max number of fraction digits that are taken into account.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+/// Splits the leading unsigned decimal number like `1.5` into the integer part, the fraction part
+/// and the rest of the string.
+///
+/// Returns `None` if the string doesn't start with a number.
+pub(crate) fn split_decimal(s: &str) -> Option<(&str, &str, &str)> {
+    let num_end = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (num, rest) = s.split_at(num_end);
+    let (int, frac) = num.split_once('.').unwrap_or((num, ""));
+    if (int.is_empty() && frac.is_empty()) || frac.contains('.') {
+        None
+    } else {
+        Some((int, frac, rest))
+    }
+}
+
+/// Multiplies the decimal number, split by ``split_decimal``, by the unit (`mul`) and truncates
+/// the fraction of the result.
+///
+/// Returns `None` on overflow.
+pub(crate) fn scale_decimal(int: &str, frac: &str, mul: u128) -> Option<u128> {
+    let int = if int.is_empty() {
+        0
+    } else {
+        int.parse::<u128>().ok()?.checked_mul(mul)?
+    };
+    let frac = &frac[..frac.len().min(MAX_FRACTION_DIGITS)];
+    if frac.is_empty() {
+        return Some(int);
+    }
+    let scale = 10u128.pow(u32::try_from(frac.len()).ok()?);
+    let frac = frac.parse::<u128>().ok()?;
+    int.checked_add(frac.checked_mul(mul)? / scale)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,7 +88,7 @@ mod tests {
         match estr.parse::<i32>() {
             Ok(res) => assert_eq!(res, -10),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -59,7 +98,7 @@ mod tests {
             #[allow(clippy::float_cmp)]
             Ok(res) => assert_eq!(res, -0.15),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -71,7 +110,7 @@ mod tests {
                 assert_eq!(reason, Reason::Parse);
             }
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -40,7 +40,6 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::structs::Pair;
 
     #[test]
     fn should_parse_empty_string_as_none() {
@@ -60,8 +59,11 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "structs")]
     #[test]
     fn should_parse_pair() {
+        use crate::structs::Pair;
+
         let estr = EString::from("1+2");
         match estr.parse::<Option<Pair<i32, '+', i32>>>() {
             Ok(res) => assert_eq!(res, Some(Pair(1, 2))),
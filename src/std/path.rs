@@ -0,0 +1,423 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// The separator of paths in the `PATH` environment variable on the current platform.
+#[cfg(windows)]
+pub const SEARCH_PATH_SEPARATOR: char = ';';
+/// The separator of paths in the `PATH` environment variable on the current platform.
+#[cfg(not(windows))]
+pub const SEARCH_PATH_SEPARATOR: char = ':';
+
+#[cfg(windows)]
+const HOME_VAR: &str = "USERPROFILE";
+#[cfg(not(windows))]
+const HOME_VAR: &str = "HOME";
+
+impl ParseFragment for PathBuf {
+    #[inline]
+    fn parse_frag(s: EString) -> crate::Result<Self> {
+        Ok(PathBuf::from(s.0))
+    }
+}
+
+impl ToEString for PathBuf {
+    #[inline]
+    fn to_estring(&self) -> EString {
+        self.as_path().to_estring()
+    }
+}
+
+impl ToEString for &Path {
+    #[inline]
+    fn to_estring(&self) -> EString {
+        EString(self.to_string_lossy().into_owned())
+    }
+}
+
+/// Wrapper for list of paths to split string by the platform separator
+/// (``SEARCH_PATH_SEPARATOR``), like the `PATH` environment variable. Empty paths are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, SearchPath, SEARCH_PATH_SEPARATOR};
+/// use std::path::PathBuf;
+///
+/// fn main() -> estring::Result<()> {
+///     let estr = EString(format!("/usr/bin{0}{0}/bin", SEARCH_PATH_SEPARATOR));
+///     let res = estr.parse::<SearchPath>()?;
+///     assert_eq!(*res, vec![PathBuf::from("/usr/bin"), PathBuf::from("/bin")]);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SearchPath(pub Vec<PathBuf>);
+
+impl std::ops::Deref for SearchPath {
+    type Target = Vec<PathBuf>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<PathBuf>> for SearchPath {
+    #[inline]
+    fn from(paths: Vec<PathBuf>) -> Self {
+        Self(paths)
+    }
+}
+
+impl ToEString for SearchPath {
+    fn to_estring(&self) -> EString {
+        let paths = self
+            .0
+            .iter()
+            .map(|path| path.to_estring().0)
+            .collect::<Vec<_>>();
+        EString(paths.join(&SEARCH_PATH_SEPARATOR.to_string()))
+    }
+}
+
+impl ParseFragment for SearchPath {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let paths = value
+            .split(SEARCH_PATH_SEPARATOR)
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .collect();
+        Ok(Self(paths))
+    }
+}
+
+/// Wrapper that allow to expand the home directory before continue
+///
+/// The leading `~`, `$HOME` or `${HOME}` is replaced by the value of the `HOME` environment
+/// variable (`USERPROFILE` on Windows) if it's followed by a path separator or ends the
+/// substring. Returns ``Reason::Missing`` if the variable is not set.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, ExpandHome};
+/// use std::path::PathBuf;
+///
+/// fn main() -> estring::Result<()> {
+///     # #[cfg(not(windows))]
+///     # if let Some(home) = std::env::var_os("HOME") {
+///     let res = EString::from("~/.config").parse::<ExpandHome<PathBuf>>()?;
+///     assert_eq!(*res, PathBuf::from(home).join(".config"));
+///     # }
+///
+///     let res = EString::from("/etc/~").parse::<ExpandHome<PathBuf>>()?;
+///     assert_eq!(*res, PathBuf::from("/etc/~"));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ExpandHome<T>(pub T);
+
+impl<T> std::ops::Deref for ExpandHome<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> ToEString for ExpandHome<T>
+where
+    T: ToEString,
+{
+    #[inline]
+    fn to_estring(&self) -> EString {
+        self.0.to_estring()
+    }
+}
+
+impl<T> ParseFragment for ExpandHome<T>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match expand_home(&value, std::env::var_os(HOME_VAR)) {
+            Ok(Some(res)) => T::parse_frag(EString(res)).map(Self),
+            Ok(None) => T::parse_frag(value).map(Self),
+            Err(reason) => Err(Error(value, reason)),
+        }
+    }
+}
+
+/// Replaces the leading home prefix of the substring with the home directory (`home`).
+///
+/// Returns `None` if the substring doesn't start with the home prefix.
+fn expand_home(value: &str, home: Option<OsString>) -> Result<Option<String>, Reason> {
+    let rest = ["~", "${HOME}", "$HOME"].iter().find_map(|prefix| {
+        value
+            .strip_prefix(prefix)
+            .filter(|rest| rest.is_empty() || rest.starts_with(std::path::is_separator))
+    });
+    match (rest, home) {
+        (None, _) => Ok(None),
+        (Some(_), None) => Err(Reason::Missing),
+        (Some(rest), Some(home)) => {
+            let mut res = home.to_string_lossy().into_owned();
+            res.push_str(rest);
+            Ok(Some(res))
+        }
+    }
+}
+
+#[doc(hidden)]
+macro_rules! checked_path_impl {
+    ($($ty:ident),+$(,)?) => {
+        $(
+            impl std::ops::Deref for $ty {
+                type Target = PathBuf;
+
+                #[inline]
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl ToEString for $ty {
+                #[inline]
+                fn to_estring(&self) -> EString {
+                    self.0.to_estring()
+                }
+            }
+
+            impl ParseFragment for $ty {
+                fn parse_frag(value: EString) -> crate::Result<Self> {
+                    let path = PathBuf::from(value.as_str());
+                    match Self::check(&path) {
+                        Ok(()) => Ok(Self(path)),
+                        Err(reason) => Err(Error(value, reason)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+/// Wrapper for ``PathBuf`` that requires the path to point at an existing file.
+///
+/// Returns ``Reason::NotFound`` if the path doesn't exist, and ``Reason::NotFile`` if it's not a
+/// file.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, ExistingFile, Reason};
+///
+/// let res = EString::from("Cargo.toml").parse::<ExistingFile>();
+/// assert!(res.is_ok());
+///
+/// let res = EString::from("src").parse::<ExistingFile>();
+/// assert_eq!(res.unwrap_err().1, Reason::NotFile);
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ExistingFile(pub PathBuf);
+
+impl ExistingFile {
+    fn check(path: &Path) -> Result<(), Reason> {
+        match path.metadata() {
+            Ok(meta) if meta.is_file() => Ok(()),
+            Ok(_) => Err(Reason::NotFile),
+            Err(_) => Err(Reason::NotFound),
+        }
+    }
+}
+
+/// Wrapper for ``PathBuf`` that requires the path to point at an existing directory.
+///
+/// Returns ``Reason::NotFound`` if the path doesn't exist, and ``Reason::NotDir`` if it's not a
+/// directory.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, ExistingDir, Reason};
+///
+/// let res = EString::from("src").parse::<ExistingDir>();
+/// assert!(res.is_ok());
+///
+/// let res = EString::from("Cargo.toml").parse::<ExistingDir>();
+/// assert_eq!(res.unwrap_err().1, Reason::NotDir);
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ExistingDir(pub PathBuf);
+
+impl ExistingDir {
+    fn check(path: &Path) -> Result<(), Reason> {
+        match path.metadata() {
+            Ok(meta) if meta.is_dir() => Ok(()),
+            Ok(_) => Err(Reason::NotDir),
+            Err(_) => Err(Reason::NotFound),
+        }
+    }
+}
+
+/// Wrapper for ``PathBuf`` that requires the path to be absolute.
+///
+/// Returns ``Reason::NotAbsolute`` if the path is relative.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{AbsolutePath, EString};
+///
+/// let path = std::env::current_dir().unwrap();
+/// assert!(EString::from(path.as_path()).parse::<AbsolutePath>().is_ok());
+/// assert!(EString::from("src/lib.rs").parse::<AbsolutePath>().is_err());
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AbsolutePath(pub PathBuf);
+
+impl AbsolutePath {
+    fn check(path: &Path) -> Result<(), Reason> {
+        if path.is_absolute() {
+            Ok(())
+        } else {
+            Err(Reason::NotAbsolute)
+        }
+    }
+}
+
+checked_path_impl![ExistingFile, ExistingDir, AbsolutePath];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest_path(path: &str) -> String {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(path)
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn should_parse_path() {
+        let estr = EString::from("/etc/hosts");
+        match estr.parse::<PathBuf>() {
+            Ok(res) => assert_eq!(res, PathBuf::from("/etc/hosts")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_search_path() {
+        let estr = EString(["a", "", "b/c", ""].join(&SEARCH_PATH_SEPARATOR.to_string()));
+        match estr.parse::<SearchPath>() {
+            Ok(res) => assert_eq!(*res, vec![PathBuf::from("a"), PathBuf::from("b/c")]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_search_path() {
+        let paths = SearchPath::from(vec![PathBuf::from("a"), PathBuf::from("b")]);
+        let estr = paths.to_estring();
+        assert_eq!(estr, EString(format!("a{SEARCH_PATH_SEPARATOR}b")));
+        assert_eq!(estr.parse::<SearchPath>(), Ok(paths));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn should_expand_home() {
+        let test_cases = [
+            ("~", "/home/user"),
+            ("~/a", "/home/user/a"),
+            ("$HOME/a", "/home/user/a"),
+            ("${HOME}", "/home/user"),
+            ("~a", "~a"),
+            ("$HOMEa", "$HOMEa"),
+            ("a/~", "a/~"),
+        ];
+
+        for (input, expected) in test_cases {
+            match expand_home(input, Some(OsString::from("/home/user"))) {
+                Ok(res) => assert_eq!(res.as_deref().unwrap_or(input), expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_missing_error_without_home() {
+        assert_eq!(expand_home("~/a", None), Err(Reason::Missing));
+        assert_eq!(expand_home("a", None), Ok(None));
+    }
+
+    #[test]
+    fn should_pass_path_without_home_prefix() {
+        let estr = EString::from("a/~");
+        match estr.parse::<ExpandHome<PathBuf>>() {
+            Ok(res) => assert_eq!(*res, PathBuf::from("a/~")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_check_existing_file() {
+        let file = EString(manifest_path("Cargo.toml"));
+        match file.parse::<ExistingFile>() {
+            Ok(res) => assert!(res.is_file()),
+            _ => unreachable!(),
+        }
+
+        let dir = EString(manifest_path("src"));
+        let res = dir.clone().parse::<ExistingFile>();
+        assert_eq!(res, Err(Error(dir, Reason::NotFile)));
+
+        let missing = EString(manifest_path("missing"));
+        let res = missing.clone().parse::<ExistingFile>();
+        assert_eq!(res, Err(Error(missing, Reason::NotFound)));
+    }
+
+    #[test]
+    fn should_check_existing_dir() {
+        let dir = EString(manifest_path("src"));
+        match dir.parse::<ExistingDir>() {
+            Ok(res) => assert!(res.is_dir()),
+            _ => unreachable!(),
+        }
+
+        let file = EString(manifest_path("Cargo.toml"));
+        let res = file.clone().parse::<ExistingDir>();
+        assert_eq!(res, Err(Error(file, Reason::NotDir)));
+
+        let missing = EString(manifest_path("missing"));
+        let res = missing.clone().parse::<ExistingDir>();
+        assert_eq!(res, Err(Error(missing, Reason::NotFound)));
+    }
+
+    #[test]
+    fn should_check_absolute_path() {
+        let estr = EString(manifest_path("src"));
+        match estr.parse::<AbsolutePath>() {
+            Ok(res) => assert!(res.is_absolute()),
+            _ => unreachable!(),
+        }
+
+        let estr = EString::from("src");
+        match estr.parse::<AbsolutePath>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("src"));
+                assert_eq!(reason, Reason::NotAbsolute);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
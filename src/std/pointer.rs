@@ -0,0 +1,134 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[doc(hidden)]
+macro_rules! from_env_string_pointer_impl {
+    ($($ty:ident),+$(,)?) => {
+        $(
+            impl<T> ParseFragment for $ty<T>
+            where
+                T: ParseFragment,
+            {
+                #[inline]
+                fn parse_frag(es: EString) -> crate::Result<Self> {
+                    T::parse_frag(es).map($ty::new)
+                }
+            }
+
+            impl<T> ToEString for $ty<T>
+            where
+                T: ToEString + ?Sized,
+            {
+                #[inline]
+                fn to_estring(&self) -> EString {
+                    (**self).to_estring()
+                }
+            }
+        )+
+    };
+}
+
+from_env_string_pointer_impl![Box, Rc, Arc];
+
+#[cfg(feature = "aggs")]
+impl<T> crate::core::Aggregatable for Box<T>
+where
+    T: crate::core::Aggregatable,
+{
+    type Item = T::Item;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        (*self).items()
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T> crate::core::Aggregatable for Rc<T>
+where
+    T: crate::core::Aggregatable + Clone,
+{
+    type Item = T::Item;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        Rc::try_unwrap(self)
+            .unwrap_or_else(|rc| (*rc).clone())
+            .items()
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T> crate::core::Aggregatable for Arc<T>
+where
+    T: crate::core::Aggregatable + Clone,
+{
+    type Item = T::Item;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        Arc::try_unwrap(self)
+            .unwrap_or_else(|arc| (*arc).clone())
+            .items()
+    }
+}
+
+impl ParseFragment for Cow<'_, str> {
+    #[inline]
+    fn parse_frag(es: EString) -> crate::Result<Self> {
+        Ok(Cow::Owned(es.0))
+    }
+}
+
+impl ToEString for Cow<'_, str> {
+    #[inline]
+    fn to_estring(&self) -> EString {
+        EString(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_into_smart_pointers() {
+        let estr = EString::from("42");
+        assert_eq!(estr.clone().parse::<Box<i32>>(), Ok(Box::new(42)));
+        assert_eq!(estr.clone().parse::<Rc<i32>>(), Ok(Rc::new(42)));
+        assert_eq!(estr.parse::<Arc<i32>>(), Ok(Arc::new(42)));
+    }
+
+    #[test]
+    fn should_parse_into_cow() {
+        let estr = EString::from("hello");
+        match estr.parse::<Cow<'_, str>>() {
+            Ok(res) => assert_eq!(res, "hello"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_smart_pointers() {
+        assert_eq!(Box::new(1).to_estring(), EString::from("1"));
+        assert_eq!(Rc::new(2).to_estring(), EString::from("2"));
+        assert_eq!(Arc::new(3).to_estring(), EString::from("3"));
+        assert_eq!(Cow::Borrowed("4").to_estring(), EString::from("4"));
+    }
+
+    #[cfg(all(feature = "aggs", feature = "structs"))]
+    #[test]
+    fn should_aggregate_items_behind_pointers() {
+        use crate::agg::Sum;
+        use crate::core::Aggregate;
+        use crate::structs::SepVec;
+
+        let estr = EString::from("1+2+3");
+        match estr.parse::<Sum<Rc<SepVec<Box<i32>, '+'>>>>() {
+            Ok(res) => assert_eq!(res.agg(), 6),
+            _ => unreachable!(),
+        }
+    }
+}
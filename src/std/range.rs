@@ -0,0 +1,267 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Splits the range into start, end and the flag whether the end is inclusive.
+pub(crate) fn split_range(value: &str) -> Option<(&str, &str, bool)> {
+    value
+        .split_once("..")
+        .map(|(start, end)| match end.strip_prefix('=') {
+            Some(end) => (start, end, true),
+            None => (start, end, false),
+        })
+}
+
+/// Parses a bound of the range.
+pub(crate) fn parse_bound<T: ParseFragment>(bound: &str) -> crate::Result<T> {
+    let bound = EString::from(bound);
+    T::parse_frag(bound.clone()).map_err(|_| Error(bound, Reason::Parse))
+}
+
+/// Checks that start of the range is not greater than its end.
+pub(crate) fn check_bounds<T: PartialOrd>(value: EString, start: &T, end: &T) -> crate::Result<()> {
+    if start > end {
+        Err(Error(value, Reason::Range))
+    } else {
+        Ok(())
+    }
+}
+
+impl<T> ParseFragment for Range<T>
+where
+    T: ParseFragment + PartialOrd,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match split_range(&value) {
+            Some((start, end, false)) if !start.is_empty() && !end.is_empty() => {
+                let (start, end) = (parse_bound(start)?, parse_bound(end)?);
+                check_bounds(value, &start, &end)?;
+                Ok(start..end)
+            }
+            _ => Err(Error(value, Reason::Split)),
+        }
+    }
+}
+
+impl<T> ToEString for Range<T>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        EString(format!(
+            "{}..{}",
+            self.start.to_estring(),
+            self.end.to_estring()
+        ))
+    }
+}
+
+impl<T> ParseFragment for RangeInclusive<T>
+where
+    T: ParseFragment + PartialOrd,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match split_range(&value) {
+            Some((start, end, true)) if !start.is_empty() && !end.is_empty() => {
+                let (start, end) = (parse_bound(start)?, parse_bound(end)?);
+                check_bounds(value, &start, &end)?;
+                Ok(start..=end)
+            }
+            _ => Err(Error(value, Reason::Split)),
+        }
+    }
+}
+
+impl<T> ToEString for RangeInclusive<T>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        EString(format!(
+            "{}..={}",
+            self.start().to_estring(),
+            self.end().to_estring()
+        ))
+    }
+}
+
+impl<T> ParseFragment for RangeFrom<T>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match split_range(&value) {
+            Some((start, "", false)) if !start.is_empty() => Ok(parse_bound(start)?..),
+            _ => Err(Error(value, Reason::Split)),
+        }
+    }
+}
+
+impl<T> ToEString for RangeFrom<T>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        EString(format!("{}..", self.start.to_estring()))
+    }
+}
+
+impl<T> ParseFragment for RangeTo<T>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match split_range(&value) {
+            Some(("", end, false)) if !end.is_empty() => Ok(..parse_bound(end)?),
+            _ => Err(Error(value, Reason::Split)),
+        }
+    }
+}
+
+impl<T> ToEString for RangeTo<T>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        EString(format!("..{}", self.end.to_estring()))
+    }
+}
+
+impl<T> ParseFragment for RangeToInclusive<T>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match split_range(&value) {
+            Some(("", end, true)) if !end.is_empty() => Ok(..=parse_bound(end)?),
+            _ => Err(Error(value, Reason::Split)),
+        }
+    }
+}
+
+impl<T> ToEString for RangeToInclusive<T>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        EString(format!("..={}", self.end.to_estring()))
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T> crate::core::Aggregatable for Range<T>
+where
+    Range<T>: Iterator<Item = T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        self.collect()
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T> crate::core::Aggregatable for RangeInclusive<T>
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        self.collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_range() {
+        let estr = EString::from("-5..10");
+        match estr.parse::<Range<i32>>() {
+            Ok(res) => assert_eq!(res, -5..10),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_inclusive_range() {
+        let estr = EString::from("1.5..=2.5");
+        match estr.parse::<RangeInclusive<f32>>() {
+            Ok(res) => assert_eq!(res, 1.5..=2.5),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_half_open_ranges() {
+        assert_eq!(
+            EString::from("8000..").parse::<RangeFrom<u16>>(),
+            Ok(8000..)
+        );
+        assert_eq!(EString::from("..10").parse::<RangeTo<u16>>(), Ok(..10));
+        assert_eq!(
+            EString::from("..=10").parse::<RangeToInclusive<u16>>(),
+            Ok(..=10)
+        );
+    }
+
+    #[test]
+    fn should_throw_split_error_on_other_range_kind() {
+        for input in ["1..=2", "1..", "..2", "1", ".."] {
+            let estr = EString::from(input);
+            match estr.parse::<Range<u16>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Split);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_range_error_on_reversed_bounds() {
+        let estr = EString::from("10..=1");
+        match estr.parse::<RangeInclusive<u16>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("10..=1"));
+                assert_eq!(reason, Reason::Range);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error_on_invalid_bound() {
+        let estr = EString::from("1..a");
+        match estr.parse::<Range<u16>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("a"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_ranges() {
+        assert_eq!((1..5).to_estring(), EString::from("1..5"));
+        assert_eq!((1..=5).to_estring(), EString::from("1..=5"));
+        assert_eq!((1..).to_estring(), EString::from("1.."));
+        assert_eq!((..5).to_estring(), EString::from("..5"));
+        assert_eq!((..=5).to_estring(), EString::from("..=5"));
+    }
+
+    #[cfg(feature = "aggs")]
+    #[test]
+    fn should_return_range_items() {
+        use crate::core::Aggregatable;
+
+        assert_eq!((1..4).items(), vec![1, 2, 3]);
+        assert_eq!((1..=4).items(), vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+
+/// Max number of decimal places of formatted percents. It hides the error of multiplication.
+const PERCENT_PRECISION: usize = 10;
+
+/// Wrapper for ratio to parse a percent (`25%`), a decimal (`0.25`) or a fraction (`1/4`) into
+/// `f64`, and format it as a decimal.
+///
+/// If `BOUNDED` is `true`, returns ``Reason::OutOfBounds`` if the ratio is not between 0 and 1
+/// (0–100%) inclusive. Returns ``Reason::Parse`` if the ratio is not finite.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Ratio, ToEString};
+///
+/// fn main() -> estring::Result<()> {
+///     for input in ["25%", "0.25", "1/4"] {
+///         let res = EString::from(input).parse::<Ratio>()?;
+///         assert_eq!(res, Ratio(0.25));
+///         assert_eq!(res.to_estring(), EString::from("0.25"));
+///     }
+///
+///     assert!(EString::from("150%").parse::<Ratio<true>>().is_err());
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct Ratio<const BOUNDED: bool = false>(pub f64);
+
+/// Wrapper for ratio that is parsed as ``Ratio``, and formatted as a percent.
+///
+/// The inner value is a ratio, so `25%` is parsed as `0.25`.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Percent, ToEString};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("1/8").parse::<Percent>()?;
+///     assert_eq!(res, Percent(0.125));
+///     assert_eq!(res.to_estring(), EString::from("12.5%"));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct Percent<const BOUNDED: bool = false>(pub f64);
+
+impl<const BOUNDED: bool> std::ops::Deref for Ratio<BOUNDED> {
+    type Target = f64;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const BOUNDED: bool> std::ops::Deref for Percent<BOUNDED> {
+    type Target = f64;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const BOUNDED: bool> std::fmt::Display for Ratio<BOUNDED> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const BOUNDED: bool> std::fmt::Display for Percent<BOUNDED> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let percent = format!("{:.*}", PERCENT_PRECISION, self.0 * 100.0);
+        let percent = percent.trim_end_matches('0').trim_end_matches('.');
+        match percent {
+            "-0" => f.write_str("0%"),
+            percent => write!(f, "{percent}%"),
+        }
+    }
+}
+
+impl<const BOUNDED: bool> ToEString for Ratio<BOUNDED> {
+    fn to_estring(&self) -> EString {
+        EString(self.to_string())
+    }
+}
+
+impl<const BOUNDED: bool> ToEString for Percent<BOUNDED> {
+    fn to_estring(&self) -> EString {
+        EString(self.to_string())
+    }
+}
+
+impl<const BOUNDED: bool> ParseFragment for Ratio<BOUNDED> {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let ratio = match parse_ratio(value.trim()) {
+            Some(ratio) => ratio,
+            None => return Err(Error(value, Reason::Parse)),
+        };
+        if BOUNDED && !(0.0..=1.0).contains(&ratio) {
+            return Err(Error(value, Reason::OutOfBounds));
+        }
+        Ok(Self(ratio))
+    }
+}
+
+impl<const BOUNDED: bool> ParseFragment for Percent<BOUNDED> {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        Ratio::<BOUNDED>::parse_frag(value).map(|Ratio(ratio)| Self(ratio))
+    }
+}
+
+/// Parses a percent, a decimal or a fraction into a finite ratio.
+fn parse_ratio(s: &str) -> Option<f64> {
+    let parse = |s: &str| s.trim().parse::<f64>().ok().filter(|n| n.is_finite());
+    let ratio = if let Some(percent) = s.strip_suffix('%') {
+        parse(percent)? / 100.0
+    } else if let Some((num, den)) = s.split_once('/') {
+        let den = parse(den).filter(|den| *den != 0.0)?;
+        parse(num)? / den
+    } else {
+        parse(s)?
+    };
+    Some(ratio).filter(|ratio| ratio.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_ratio_in_all_forms() {
+        let test_cases = [
+            ("25%", 0.25),
+            ("25 %", 0.25),
+            ("0.25", 0.25),
+            ("1/4", 0.25),
+            ("1 / 4", 0.25),
+            ("150%", 1.5),
+            ("-3/2", -1.5),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<Ratio>() {
+                Ok(res) => assert_eq!(res, Ratio(expected)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        for input in [
+            "",
+            "%",
+            "abc",
+            "1/0",
+            "1/",
+            "1/2/3",
+            "inf",
+            "NaN%",
+            "1e308/1e-308",
+        ] {
+            let estr = EString::from(input);
+            match estr.parse::<Ratio>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Parse);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_out_of_bounds_error() {
+        for input in ["101%", "-0.1", "5/4"] {
+            let estr = EString::from(input);
+            match estr.parse::<Percent<true>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::OutOfBounds);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        for input in ["0%", "100%", "1/1"] {
+            assert!(EString::from(input).parse::<Percent<true>>().is_ok());
+        }
+    }
+
+    #[test]
+    fn should_format_ratio() {
+        assert_eq!(Ratio::<false>(0.5).to_estring(), EString::from("0.5"));
+        assert_eq!(Ratio::<false>(1.0).to_estring(), EString::from("1"));
+    }
+
+    #[test]
+    fn should_format_percent() {
+        let test_cases = [
+            (0.07, "7%"),
+            (0.125, "12.5%"),
+            (1.0, "100%"),
+            (0.0, "0%"),
+            (-0.0, "0%"),
+            (-0.5, "-50%"),
+            (1.0 / 3.0, "33.3333333333%"),
+        ];
+
+        for (ratio, expected) in test_cases {
+            assert_eq!(
+                Percent::<false>(ratio).to_estring(),
+                EString::from(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn should_parse_formatted_percent_back() {
+        let percent = EString::from("7%").parse::<Percent>().unwrap();
+        assert_eq!(percent.to_estring().parse::<Percent>(), Ok(percent));
+    }
+}
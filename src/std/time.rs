@@ -0,0 +1,672 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+use std::marker::PhantomData;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECS_PER_DAY: i128 = 86_400;
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Year, month and day.
+pub(crate) type DateParts = (i64, u32, u32);
+/// Hour, minute, second and nanosecond.
+pub(crate) type TimeParts = (u32, u32, u32, u32);
+
+/// Trait to represent formats of time types (`T`) to use with ``Formatted``.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Formatted, TimeFormat, ToEString};
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// struct UnixMillis;
+///
+/// impl TimeFormat<SystemTime> for UnixMillis {
+///     fn parse(s: &str) -> Option<SystemTime> {
+///         s.parse().ok().and_then(|ms| UNIX_EPOCH.checked_add(Duration::from_millis(ms)))
+///     }
+///
+///     fn format(value: &SystemTime) -> String {
+///         let ms = value.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+///         ms.to_string()
+///     }
+/// }
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("1500").parse::<Formatted<SystemTime, UnixMillis>>()?;
+///     assert_eq!(*res, UNIX_EPOCH + Duration::from_millis(1500));
+///     assert_eq!(res.to_estring(), EString::from("1500"));
+///     Ok(())
+/// }
+/// ```
+///
+pub trait TimeFormat<T> {
+    /// Parses the string, or returns `None` if it's not in the format.
+    fn parse(s: &str) -> Option<T>;
+
+    /// Formats the value.
+    fn format(value: &T) -> String;
+}
+
+/// RFC 3339 format, e.g. `2024-01-02T03:04:05.5+02:00`. ``SystemTime`` is formatted in UTC,
+/// as well as values with an offset that has seconds, because RFC 3339 doesn't support them.
+///
+/// Years out of `0000..=9999` are written with a sign and at least 4 digits, e.g.
+/// `-0001-01-01T00:00:00Z` or `+10000-01-01T00:00:00Z`.
+pub struct Rfc3339;
+
+/// Unix timestamp in seconds with an optional fraction, e.g. `1700000000` or `-1.5`.
+pub struct UnixTimestamp;
+
+/// Wrapper for time types (`T`) to parse and format them in a format (`F`).
+///
+/// Returns ``Reason::Parse`` if the string is not in the format.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Formatted, Rfc3339, ToEString, UnixTimestamp};
+/// use std::time::SystemTime;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("86400").parse::<Formatted<SystemTime, UnixTimestamp>>()?;
+///     let res = Formatted::<_, Rfc3339>::from(*res);
+///     assert_eq!(res.to_estring(), EString::from("1970-01-02T00:00:00Z"));
+///     Ok(())
+/// }
+/// ```
+///
+pub struct Formatted<T, F>(pub T, PhantomData<F>);
+
+impl<T, F> std::fmt::Debug for Formatted<T, F>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Formatted").field(&self.0).finish()
+    }
+}
+
+impl<T, F> PartialEq for Formatted<T, F>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, F> Clone for Formatted<T, F>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from(self.0.clone())
+    }
+}
+
+impl<T, F> std::ops::Deref for Formatted<T, F> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, F> From<T> for Formatted<T, F> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<T, F> ToEString for Formatted<T, F>
+where
+    F: TimeFormat<T>,
+{
+    fn to_estring(&self) -> EString {
+        EString(F::format(&self.0))
+    }
+}
+
+impl<T, F> ParseFragment for Formatted<T, F>
+where
+    F: TimeFormat<T>,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        match F::parse(value.trim()) {
+            Some(res) => Ok(Self::from(res)),
+            None => Err(Error(value, Reason::Parse)),
+        }
+    }
+}
+
+/// Returns the number of days since the Unix epoch of the date in the proleptic Gregorian
+/// calendar.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i128 {
+    let year = i128::from(year) - i128::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = i128::from(month);
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i128::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the date in the proleptic Gregorian calendar of the number of days since the Unix
+/// epoch.
+pub(crate) fn civil_from_days(days: i128) -> (i128, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i128::from(month <= 2);
+    // month and day are always in range
+    (
+        year,
+        u32::try_from(month).unwrap_or_default(),
+        u32::try_from(day).unwrap_or_default(),
+    )
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Parses fixed number of digits.
+fn digits<T: std::str::FromStr>(s: &str, len: usize) -> Option<T> {
+    s.get(..len)
+        .filter(|d| d.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|d| d.parse().ok())
+}
+
+/// Parses fraction of a second into nanoseconds. Digits after nanoseconds are truncated.
+fn fraction_nanos(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let s = &s[..s.len().min(9)];
+    let scale = 10u32.pow(u32::try_from(9 - s.len()).ok()?);
+    s.parse::<u32>().ok().map(|n| n * scale)
+}
+
+/// Parses a year of 4 digits, or a year with a sign and at least 4 digits.
+fn parse_year(s: &str) -> Option<i64> {
+    let (negative, digits) = match s.as_bytes().first()? {
+        b'+' => (false, &s[1..]),
+        b'-' => (true, &s[1..]),
+        _ if s.len() == 4 => (false, s),
+        _ => return None,
+    };
+    if digits.len() < 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let year = digits.parse::<i64>().ok()?;
+    Some(if negative { -year } else { year })
+}
+
+/// Parses `YYYY-MM-DD` date into a valid year, month and day.
+///
+/// Years out of `0000..=9999` have a sign and at least 4 digits, e.g. `-0001` or `+10000`, as in
+/// the expanded representation of ISO 8601.
+pub(crate) fn parse_date(s: &str) -> Option<DateParts> {
+    let b = s.as_bytes();
+    let year_len = b.len().checked_sub(6).filter(|len| *len >= 4)?;
+    if b[year_len] != b'-' || b[year_len + 3] != b'-' {
+        return None;
+    }
+    let year = parse_year(&s[..year_len])?;
+    let month = digits::<u32>(&s[year_len + 1..], 2).filter(|m| (1..=12).contains(m))?;
+    let day = digits::<u32>(&s[year_len + 4..], 2)
+        .filter(|d| (1..=days_in_month(year, month)).contains(d))?;
+    Some((year, month, day))
+}
+
+/// Parses `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fraction` time into a valid hour, minute, second and
+/// nanosecond.
+///
+/// The leap second `60` is parsed as the second `59` with nanoseconds greater than or equal to one
+/// second, as ``chrono`` represents it.
+pub(crate) fn parse_time(s: &str) -> Option<TimeParts> {
+    let b = s.as_bytes();
+    if b.len() < 5 || b[2] != b':' {
+        return None;
+    }
+    let hour = digits::<u32>(s, 2).filter(|h| *h < 24)?;
+    let min = digits::<u32>(&s[3..], 2).filter(|m| *m < 60)?;
+    let rest = &s[5..];
+    if rest.is_empty() {
+        return Some((hour, min, 0, 0));
+    }
+
+    let rest = rest.strip_prefix(':')?;
+    let sec = digits::<u32>(rest, 2).filter(|s| *s <= 60)?;
+    let nanos = match rest.get(2..)? {
+        "" => 0,
+        frac => fraction_nanos(frac.strip_prefix('.')?)?,
+    };
+    if sec == 60 {
+        Some((hour, min, 59, nanos + NANOS_PER_SEC))
+    } else {
+        Some((hour, min, sec, nanos))
+    }
+}
+
+/// Parses date and time separated by `T` or a space.
+pub(crate) fn parse_date_time(s: &str) -> Option<(DateParts, TimeParts)> {
+    let (date, time) = s.split_once(&['T', 't', ' '][..])?;
+    parse_date(date).zip(parse_time(time))
+}
+
+/// Formats date as `YYYY-MM-DD`. Years out of `0000..=9999` are formatted with a sign, so the
+/// date is parsed back by ``parse_date``.
+pub(crate) fn format_date(year: i128, month: u32, day: u32) -> String {
+    if (0..=9_999).contains(&year) {
+        format!("{year:04}-{month:02}-{day:02}")
+    } else {
+        let sign = if year < 0 { '-' } else { '+' };
+        format!("{sign}{:04}-{month:02}-{day:02}", year.unsigned_abs())
+    }
+}
+
+/// Formats time as `HH:MM:SS` with an optional fraction.
+///
+/// Nanoseconds greater than or equal to one second represent the leap second, which is formatted
+/// as the second `60`.
+pub(crate) fn format_time(hour: u32, min: u32, sec: u32, nanos: u32) -> String {
+    let (sec, nanos) = if nanos >= NANOS_PER_SEC {
+        (sec + 1, nanos - NANOS_PER_SEC)
+    } else {
+        (sec, nanos)
+    };
+    let mut res = format!("{hour:02}:{min:02}:{sec:02}");
+    push_fraction(&mut res, nanos);
+    res
+}
+
+/// Pushes fraction of a second without trailing zeros.
+fn push_fraction(res: &mut String, nanos: u32) {
+    if nanos > 0 {
+        let frac = format!("{nanos:09}");
+        res.push('.');
+        res.push_str(frac.trim_end_matches('0'));
+    }
+}
+
+/// Parses RFC 3339 date and time into the local date, the local time and the UTC offset in
+/// seconds.
+pub(crate) fn parse_rfc3339_local(s: &str) -> Option<(DateParts, TimeParts, i32)> {
+    let time_start = s.find(&['T', 't', ' '][..])? + 1;
+    let offset_start = s[time_start..].find(&['Z', 'z', '+', '-'][..])? + time_start;
+    // seconds are required
+    if offset_start - time_start < 8 {
+        return None;
+    }
+    let (date_time, offset) = s.split_at(offset_start);
+
+    let offset = match offset {
+        "Z" | "z" => 0,
+        _ => {
+            let ob = offset.as_bytes();
+            if ob.len() != 6 || ob[3] != b':' {
+                return None;
+            }
+            let oh = digits::<i32>(&offset[1..], 2).filter(|h| *h < 24)?;
+            let om = digits::<i32>(&offset[4..], 2).filter(|m| *m < 60)?;
+            let sign = if ob[0] == b'-' { -1 } else { 1 };
+            sign * (oh * 3_600 + om * 60)
+        }
+    };
+    let (date, time) = parse_date_time(date_time)?;
+    Some((date, time, offset))
+}
+
+/// Parses RFC 3339 date and time into seconds and nanoseconds since the Unix epoch.
+pub(crate) fn parse_rfc3339(s: &str) -> Option<(i128, u32)> {
+    let ((year, month, day), (hour, min, sec, nanos), offset) = parse_rfc3339_local(s)?;
+
+    // the leap second is counted as the next second
+    let time = i128::from(hour * 3_600 + min * 60 + sec + nanos / NANOS_PER_SEC);
+    let secs = days_from_civil(year, month, day) * SECS_PER_DAY + time - i128::from(offset);
+    Some((secs, nanos % NANOS_PER_SEC))
+}
+
+/// Formats the local date, the local time and the UTC offset in seconds as RFC 3339 date and time.
+///
+/// Returns `None` if the offset has seconds, because RFC 3339 doesn't support them.
+pub(crate) fn format_rfc3339_local(
+    (year, month, day): (i128, u32, u32),
+    (hour, min, sec, nanos): TimeParts,
+    offset: i32,
+) -> Option<String> {
+    let zone = match offset {
+        0 => String::from("Z"),
+        _ if offset % 60 != 0 => return None,
+        _ => {
+            let sign = if offset < 0 { '-' } else { '+' };
+            let offset = offset.unsigned_abs() / 60;
+            format!("{sign}{:02}:{:02}", offset / 60, offset % 60)
+        }
+    };
+    Some(format!(
+        "{}T{}{zone}",
+        format_date(year, month, day),
+        format_time(hour, min, sec, nanos)
+    ))
+}
+
+/// Formats seconds and nanoseconds since the Unix epoch as RFC 3339 date and time in UTC.
+pub(crate) fn format_rfc3339(secs: i128, nanos: u32) -> String {
+    let (year, month, day) = civil_from_days(secs.div_euclid(SECS_PER_DAY));
+    // the remainder is always less than a day
+    let rem = u32::try_from(secs.rem_euclid(SECS_PER_DAY)).unwrap_or_default();
+    // the zero offset is always supported
+    format_rfc3339_local(
+        (year, month, day),
+        (rem / 3_600, rem / 60 % 60, rem % 60, nanos),
+        0,
+    )
+    .unwrap_or_default()
+}
+
+/// Parses Unix timestamp into seconds and nanoseconds since the Unix epoch.
+pub(crate) fn parse_unix_timestamp(s: &str) -> Option<(i128, u32)> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (secs, frac) = s.split_once('.').map_or((s, None), |(s, f)| (s, Some(f)));
+    if secs.is_empty() || !secs.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let secs = secs.parse::<i128>().ok()?;
+    let nanos = frac.map_or(Some(0), fraction_nanos)?;
+    Some(match (negative, nanos) {
+        (false, _) => (secs, nanos),
+        (true, 0) => (-secs, 0),
+        (true, nanos) => (-secs - 1, NANOS_PER_SEC - nanos),
+    })
+}
+
+/// Formats seconds and nanoseconds since the Unix epoch as Unix timestamp.
+pub(crate) fn format_unix_timestamp(secs: i128, nanos: u32) -> String {
+    let (mut res, nanos) = if secs < 0 && nanos > 0 {
+        (format!("-{}", -secs - 1), NANOS_PER_SEC - nanos)
+    } else {
+        (secs.to_string(), nanos)
+    };
+    push_fraction(&mut res, nanos);
+    res
+}
+
+fn system_time_from_parts((secs, nanos): (i128, u32)) -> Option<SystemTime> {
+    if secs >= 0 {
+        let secs = u64::try_from(secs).ok()?;
+        UNIX_EPOCH.checked_add(Duration::new(secs, nanos))
+    } else {
+        let secs = u64::try_from(-secs).ok()?;
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(secs))?
+            .checked_add(Duration::from_nanos(u64::from(nanos)))
+    }
+}
+
+fn system_time_to_parts(value: &SystemTime) -> (i128, u32) {
+    match value.duration_since(UNIX_EPOCH) {
+        Ok(dur) => (i128::from(dur.as_secs()), dur.subsec_nanos()),
+        Err(err) => {
+            let dur = err.duration();
+            let secs = -i128::from(dur.as_secs());
+            match dur.subsec_nanos() {
+                0 => (secs, 0),
+                nanos => (secs - 1, NANOS_PER_SEC - nanos),
+            }
+        }
+    }
+}
+
+impl TimeFormat<SystemTime> for Rfc3339 {
+    fn parse(s: &str) -> Option<SystemTime> {
+        parse_rfc3339(s).and_then(system_time_from_parts)
+    }
+
+    fn format(value: &SystemTime) -> String {
+        let (secs, nanos) = system_time_to_parts(value);
+        format_rfc3339(secs, nanos)
+    }
+}
+
+impl TimeFormat<SystemTime> for UnixTimestamp {
+    fn parse(s: &str) -> Option<SystemTime> {
+        parse_unix_timestamp(s).and_then(system_time_from_parts)
+    }
+
+    fn format(value: &SystemTime) -> String {
+        let (secs, nanos) = system_time_to_parts(value);
+        format_unix_timestamp(secs, nanos)
+    }
+}
+
+impl ParseFragment for SystemTime {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let s = value.trim();
+        match Rfc3339::parse(s).or_else(|| UnixTimestamp::parse(s)) {
+            Some(res) => Ok(res),
+            None => Err(Error(value, Reason::Parse)),
+        }
+    }
+}
+
+impl ToEString for SystemTime {
+    fn to_estring(&self) -> EString {
+        EString(Rfc3339::format(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(secs: i64, nanos: u32) -> SystemTime {
+        match u64::try_from(secs) {
+            Ok(secs) => UNIX_EPOCH + Duration::new(secs, nanos),
+            Err(_) => {
+                UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs()) + Duration::new(0, nanos)
+            }
+        }
+    }
+
+    #[test]
+    fn should_convert_days_and_dates() {
+        let test_cases = [
+            ((1970, 1, 1), 0),
+            ((2000, 3, 1), 11_017),
+            ((2024, 2, 29), 19_782),
+            ((1969, 12, 31), -1),
+            ((1600, 1, 1), -135_140),
+        ];
+
+        for ((y, m, d), days) in test_cases {
+            assert_eq!(days_from_civil(y, m, d), days);
+            assert_eq!(civil_from_days(days), (i128::from(y), m, d));
+        }
+    }
+
+    #[test]
+    fn should_parse_date_and_time() {
+        assert_eq!(parse_date("2024-02-29"), Some((2024, 2, 29)));
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2024-1-02"), None);
+        assert_eq!(parse_date("-0001-01-01"), Some((-1, 1, 1)));
+        assert_eq!(parse_date("+10000-12-31"), Some((10_000, 12, 31)));
+        assert_eq!(parse_date("10000-12-31"), None);
+        assert_eq!(parse_date("-001-01-01"), None);
+
+        assert_eq!(parse_time("03:04"), Some((3, 4, 0, 0)));
+        assert_eq!(parse_time("03:04:05.5"), Some((3, 4, 5, 500_000_000)));
+        assert_eq!(parse_time("23:59:60.5"), Some((23, 59, 59, 1_500_000_000)));
+        assert_eq!(parse_time("03:04:61"), None);
+        assert_eq!(parse_time("03:04:05."), None);
+
+        assert_eq!(
+            parse_date_time("2024-01-02 03:04"),
+            Some(((2024, 1, 2), (3, 4, 0, 0)))
+        );
+        assert_eq!(parse_date_time("2024-01-02"), None);
+    }
+
+    #[test]
+    fn should_format_date_at_range_edges() {
+        let test_cases = [
+            ((0, 1, 1), "0000-01-01"),
+            ((9_999, 12, 31), "9999-12-31"),
+            ((-1, 1, 1), "-0001-01-01"),
+            ((10_000, 1, 1), "+10000-01-01"),
+            ((-262_143, 1, 1), "-262143-01-01"),
+        ];
+
+        for ((year, month, day), expected) in test_cases {
+            let res = format_date(year, month, day);
+            assert_eq!(res, expected);
+            assert_eq!(
+                parse_date(&res),
+                i64::try_from(year).ok().map(|y| (y, month, day))
+            );
+        }
+    }
+
+    #[test]
+    fn should_format_leap_second() {
+        assert_eq!(format_time(23, 59, 59, 1_500_000_000), "23:59:60.5");
+        assert_eq!(format_time(23, 59, 59, NANOS_PER_SEC), "23:59:60");
+        assert_eq!(parse_time("23:59:60"), Some((23, 59, 59, NANOS_PER_SEC)));
+    }
+
+    #[test]
+    fn should_parse_rfc3339() {
+        let test_cases = [
+            ("1970-01-01T00:00:00Z", time(0, 0)),
+            ("2024-01-02T03:04:05Z", time(1_704_164_645, 0)),
+            ("2024-01-02t03:04:05.25z", time(1_704_164_645, 250_000_000)),
+            ("2024-01-02 05:04:05+02:00", time(1_704_164_645, 0)),
+            ("2024-01-01T23:04:05-04:00", time(1_704_164_645, 0)),
+            ("1969-12-31T23:59:59.5Z", time(-1, 500_000_000)),
+            ("2016-12-31T23:59:60.5Z", time(1_483_228_800, 500_000_000)),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<SystemTime>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_rfc3339_out_of_four_digit_years() {
+        let test_cases = [
+            (time(253_402_300_800, 0), "+10000-01-01T00:00:00Z"),
+            (time(-62_198_755_200, 0), "-0001-01-01T00:00:00Z"),
+        ];
+
+        for (value, expected) in test_cases {
+            let estr = value.to_estring();
+            assert_eq!(estr, EString::from(expected));
+            assert_eq!(estr.parse::<SystemTime>(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn should_parse_unix_timestamp() {
+        let test_cases = [
+            ("0", time(0, 0)),
+            ("1704164645", time(1_704_164_645, 0)),
+            ("1.000000001", time(1, 1)),
+            ("-1.5", time(-2, 500_000_000)),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<SystemTime>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        let test_cases = [
+            "",
+            "2024-01-02",
+            "2024-13-02T03:04:05Z",
+            "2023-02-29T03:04:05Z",
+            "2024-01-02T24:04:05Z",
+            "2024-01-02T03:04:05",
+            "2024-01-02T03:04:05.Z",
+            "2024-01-02T03:04:05+0200",
+            "1.",
+            "abc",
+        ];
+
+        for input in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<SystemTime>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Parse);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_pick_format() {
+        let estr = EString::from("2024-01-02T03:04:05Z");
+        assert!(estr
+            .clone()
+            .parse::<Formatted<SystemTime, Rfc3339>>()
+            .is_ok());
+        assert!(estr
+            .parse::<Formatted<SystemTime, UnixTimestamp>>()
+            .is_err());
+
+        let estr = EString::from("1704164645");
+        assert!(estr
+            .clone()
+            .parse::<Formatted<SystemTime, Rfc3339>>()
+            .is_err());
+        assert!(estr.parse::<Formatted<SystemTime, UnixTimestamp>>().is_ok());
+    }
+
+    #[test]
+    fn should_format_system_time() {
+        let test_cases = [
+            (time(1_704_164_645, 0), "2024-01-02T03:04:05Z", "1704164645"),
+            (time(0, 120_000_000), "1970-01-01T00:00:00.12Z", "0.12"),
+            (time(-2, 500_000_000), "1969-12-31T23:59:58.5Z", "-1.5"),
+        ];
+
+        for (time, rfc3339, timestamp) in test_cases {
+            let estr = time.to_estring();
+            assert_eq!(estr, EString::from(rfc3339));
+            assert_eq!(estr.parse::<SystemTime>(), Ok(time));
+
+            let estr = Formatted::<_, UnixTimestamp>::from(time).to_estring();
+            assert_eq!(estr, EString::from(timestamp));
+            assert_eq!(estr.parse::<SystemTime>(), Ok(time));
+        }
+    }
+}
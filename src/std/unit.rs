@@ -0,0 +1,51 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::error::{Error, Reason};
+
+impl ParseFragment for () {
+    #[inline]
+    fn parse_frag(s: EString) -> crate::Result<Self> {
+        if s.is_empty() {
+            Ok(())
+        } else {
+            Err(Error(s, Reason::Parse))
+        }
+    }
+}
+
+impl ToEString for () {
+    #[inline]
+    fn to_estring(&self) -> EString {
+        EString::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_empty_string_as_unit() {
+        let estr = EString::new();
+        match estr.parse::<()>() {
+            Ok(res) => assert_eq!(res, ()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        let estr = EString::from(" ");
+        match estr.parse::<()>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from(" "));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_unit() {
+        assert_eq!(().to_estring(), EString::new());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::core::{EString, ParseFragment, ToEString};
+use std::num::Wrapping;
+
+impl<T> ParseFragment for Wrapping<T>
+where
+    T: ParseFragment,
+{
+    #[inline]
+    fn parse_frag(es: EString) -> crate::Result<Self> {
+        T::parse_frag(es).map(Wrapping)
+    }
+}
+
+impl<T> ToEString for Wrapping<T>
+where
+    T: ToEString,
+{
+    #[inline]
+    fn to_estring(&self) -> EString {
+        self.0.to_estring()
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T> crate::core::Aggregatable for Wrapping<T> {
+    type Item = Self;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        vec![self]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_wrapping_number() {
+        let estr = EString::from("255");
+        match estr.parse::<Wrapping<u8>>() {
+            Ok(res) => assert_eq!(res + Wrapping(1), Wrapping(0)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_wrapping_number() {
+        assert_eq!(Wrapping(-1).to_estring(), EString::from("-1"));
+    }
+
+    #[cfg(all(feature = "aggs", feature = "structs"))]
+    #[test]
+    fn should_sum_with_wrapping() {
+        use crate::agg::Sum;
+        use crate::core::Aggregate;
+        use crate::structs::SepVec;
+
+        let estr = EString::from("200+100");
+        match estr.parse::<Sum<SepVec<Wrapping<u8>, '+'>>>() {
+            Ok(res) => assert_eq!(res.agg(), Wrapping(44)),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -3,10 +3,67 @@
 //! **NOTE**: Require the enabling the `structs` feature.
 //!
 
+mod any_range;
+mod bounded_sep_vec;
+mod bracketed;
+mod byte_size;
+mod cidr;
+mod collection;
+mod counted;
+mod dash_range;
+mod default_pair;
+mod either;
+mod fields;
+mod lines;
+mod nth_pair;
+mod nth_trio;
+mod one_of;
+mod opt_pair;
 mod pair;
+mod quoted_pair;
+mod repeat;
+mod rpair;
+mod rtrio;
+mod sep_array;
+mod sep_map;
+mod sep_set;
+mod sep_tuple;
 mod sep_vec;
+mod sep_vec_with;
+mod split;
 mod trio;
+mod url;
 
+pub use any_range::*;
+pub use bounded_sep_vec::*;
+pub use bracketed::*;
+pub use byte_size::*;
+pub use cidr::*;
+pub use collection::*;
+pub use counted::*;
+pub use dash_range::*;
+pub use default_pair::*;
+pub use either::*;
+pub use fields::*;
+pub use lines::*;
+pub use nth_pair::*;
+pub use nth_trio::*;
+pub use one_of::*;
+pub use opt_pair::*;
 pub use pair::*;
+pub use quoted_pair::*;
+pub use repeat::*;
+pub use rpair::*;
+pub use rtrio::*;
+pub use sep_array::*;
+pub use sep_map::*;
+pub use sep_set::*;
+pub use sep_tuple::*;
 pub use sep_vec::*;
+pub use sep_vec_with::*;
+pub use split::{
+    BracketAware, EmptyInputAsEmptyVec, EmptyPolicy, Plain, QuoteAware, Raw, RejectEmpty,
+    SkipEmpty, SplitMode,
+};
 pub use trio::*;
+pub use url::*;
@@ -0,0 +1,176 @@
+//! Contains the implementations to range type of any kind
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::std::range::{check_bounds, parse_bound, split_range};
+use crate::{Error, Reason};
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Range of any kind: `a..b`, `a..=b`, `a..`, `..b` or `..=b`.
+///
+/// Returns ``Reason::Range`` if start of the range is greater than its end.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{AnyRange, EString};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("8000..=8080").parse::<AnyRange<u16>>()?;
+///     assert_eq!(res, AnyRange::Inclusive(8000..=8080));
+///     assert!(res.contains(&8080));
+///
+///     let res = EString::from("1024..").parse::<AnyRange<u16>>()?;
+///     assert_eq!(res, AnyRange::From(1024..));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AnyRange<T> {
+    /// Range `a..b`
+    Exclusive(Range<T>),
+    /// Range `a..=b`
+    Inclusive(RangeInclusive<T>),
+    /// Range `a..`
+    From(RangeFrom<T>),
+    /// Range `..b`
+    To(RangeTo<T>),
+    /// Range `..=b`
+    ToInclusive(RangeToInclusive<T>),
+}
+
+impl<T> AnyRange<T>
+where
+    T: PartialOrd,
+{
+    /// Returns `true` if `item` is contained in the range.
+    pub fn contains(&self, item: &T) -> bool {
+        match self {
+            Self::Exclusive(range) => range.contains(item),
+            Self::Inclusive(range) => range.contains(item),
+            Self::From(range) => range.contains(item),
+            Self::To(range) => range.contains(item),
+            Self::ToInclusive(range) => range.contains(item),
+        }
+    }
+}
+
+impl<T> std::fmt::Display for AnyRange<T>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exclusive(range) => write!(f, "{}..{}", range.start, range.end),
+            Self::Inclusive(range) => write!(f, "{}..={}", range.start(), range.end()),
+            Self::From(range) => write!(f, "{}..", range.start),
+            Self::To(range) => write!(f, "..{}", range.end),
+            Self::ToInclusive(range) => write!(f, "..={}", range.end),
+        }
+    }
+}
+
+impl<T> ToEString for AnyRange<T>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        match self {
+            Self::Exclusive(range) => range.to_estring(),
+            Self::Inclusive(range) => range.to_estring(),
+            Self::From(range) => range.to_estring(),
+            Self::To(range) => range.to_estring(),
+            Self::ToInclusive(range) => range.to_estring(),
+        }
+    }
+}
+
+impl<T> ParseFragment for AnyRange<T>
+where
+    T: ParseFragment + PartialOrd,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let (start, end, inclusive) = match split_range(&value) {
+            Some(parts) => parts,
+            None => return Err(Error(value, Reason::Split)),
+        };
+
+        match (start, end, inclusive) {
+            ("", "", _) | (_, "", true) => Err(Error(value, Reason::Split)),
+            (start, "", false) => Ok(Self::From(parse_bound(start)?..)),
+            ("", end, false) => Ok(Self::To(..parse_bound(end)?)),
+            ("", end, true) => Ok(Self::ToInclusive(..=parse_bound(end)?)),
+            (start, end, inclusive) => {
+                let (start, end) = (parse_bound(start)?, parse_bound(end)?);
+                check_bounds(value, &start, &end)?;
+                if inclusive {
+                    Ok(Self::Inclusive(start..=end))
+                } else {
+                    Ok(Self::Exclusive(start..end))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_any_range() {
+        let test_cases = [
+            ("1..5", AnyRange::Exclusive(1..5)),
+            ("1..=5", AnyRange::Inclusive(1..=5)),
+            ("1..", AnyRange::From(1..)),
+            ("..5", AnyRange::To(..5)),
+            ("..=5", AnyRange::ToInclusive(..=5)),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<AnyRange<i32>>() {
+                Ok(res) => {
+                    assert_eq!(res, expected);
+                    assert_eq!(res.to_estring(), EString::from(input));
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error() {
+        for input in ["..", "1..=", "1"] {
+            let estr = EString::from(input);
+            match estr.parse::<AnyRange<i32>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Split);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_range_error() {
+        let estr = EString::from("5..1");
+        match estr.parse::<AnyRange<i32>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("5..1"));
+                assert_eq!(reason, Reason::Range);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_check_whether_range_contains_item() {
+        assert!(AnyRange::From(5..).contains(&10));
+        assert!(!AnyRange::Exclusive(1..5).contains(&5));
+    }
+}
@@ -0,0 +1,210 @@
+//! Contains the implementations to length-constrained vec type
+//!
+
+use super::split::{self, EmptyInputAsEmptyVec};
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for ``Vec`` to split string by a separator (`SEP`) and require from `MIN` to `MAX`
+/// elements inclusive.
+///
+/// Each element is trimmed before parsing, and an empty fragment is parsed as a vec without
+/// elements. Returns ``Reason::TooFew`` or ``Reason::TooMany`` if the number of elements is out
+/// of bounds.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{BoundedSepVec, EString, Error, Reason};
+///
+/// type Workers = BoundedSepVec<u16, ',', 1, 8>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("8001,8002").parse::<Workers>()?;
+///     assert_eq!(*res, vec![8001, 8002]);
+///
+///     let res = EString::new().parse::<Workers>();
+///     assert_eq!(res, Err(Error(EString::new(), Reason::TooFew { min: 1, actual: 0 })));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct BoundedSepVec<T, const SEP: char, const MIN: usize, const MAX: usize>(pub Vec<T>);
+
+/// Wrapper for ``Vec`` to split string by a separator (`SEP`) and require at least one element.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, NonEmptySepVec};
+///
+/// type Hosts = NonEmptySepVec<&'static str, ','>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("db1,db2").parse::<Hosts>()?;
+///     assert_eq!(*res, vec!["db1", "db2"]);
+///     assert!(EString::new().parse::<Hosts>().is_err());
+///     Ok(())
+/// }
+/// ```
+///
+pub type NonEmptySepVec<T, const SEP: char> = BoundedSepVec<T, SEP, 1, { usize::MAX }>;
+
+impl<T, const SEP: char, const MIN: usize, const MAX: usize> std::ops::Deref
+    for BoundedSepVec<T, SEP, MIN, MAX>
+{
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const SEP: char, const MIN: usize, const MAX: usize> From<Vec<T>>
+    for BoundedSepVec<T, SEP, MIN, MAX>
+{
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        Self(vec)
+    }
+}
+
+impl<T, const SEP: char, const MIN: usize, const MAX: usize> std::fmt::Display
+    for BoundedSepVec<T, SEP, MIN, MAX>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().enumerate().try_for_each(|(i, part)| {
+            if i != 0 {
+                f.write_char(SEP)?;
+            }
+
+            write!(f, "{part}")
+        })
+    }
+}
+
+impl<T, const SEP: char, const MIN: usize, const MAX: usize> ToEString
+    for BoundedSepVec<T, SEP, MIN, MAX>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        self.0
+            .iter()
+            .enumerate()
+            .try_fold(String::new(), |mut res, (i, part)| {
+                if i != 0 {
+                    res.write_char(SEP).ok()?;
+                }
+
+                write!(res, "{}", part.to_estring()).ok()?;
+                Some(res)
+            })
+            .map(EString)
+            .expect("Cannot format BoundedSepVec ${self.0} to EString")
+    }
+}
+
+impl<T, const SEP: char, const MIN: usize, const MAX: usize> ParseFragment
+    for BoundedSepVec<T, SEP, MIN, MAX>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let parts = split::elements::<EmptyInputAsEmptyVec>(&value, SEP)?;
+        let actual = parts.len();
+        if actual < MIN {
+            return Err(Error(value, Reason::TooFew { min: MIN, actual }));
+        }
+        if actual > MAX {
+            return Err(Error(value, Reason::TooMany { max: MAX, actual }));
+        }
+
+        let inner = parts
+            .into_iter()
+            .map(T::parse_frag)
+            .collect::<crate::Result<Vec<_>>>()?;
+        Ok(Self(inner))
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const SEP: char, const MIN: usize, const MAX: usize> crate::core::Aggregatable
+    for BoundedSepVec<T, SEP, MIN, MAX>
+where
+    T: crate::core::Aggregatable,
+{
+    type Item = T::Item;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0.into_iter().flat_map(T::items).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Workers = BoundedSepVec<i32, ',', 2, 3>;
+
+    #[test]
+    fn should_parse_vec_in_bounds() {
+        for (input, expected) in [("1,2", vec![1, 2]), ("1,2,3", vec![1, 2, 3])] {
+            let estr = EString::from(input);
+            match estr.parse::<Workers>() {
+                Ok(res) => assert_eq!(*res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_too_few_error() {
+        let estr = EString::from("1");
+        match estr.parse::<Workers>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("1"));
+                assert_eq!(reason, Reason::TooFew { min: 2, actual: 1 });
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_too_many_error() {
+        let estr = EString::from("1,2,3,4");
+        match estr.parse::<Workers>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("1,2,3,4"));
+                assert_eq!(reason, Reason::TooMany { max: 3, actual: 4 });
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_too_few_error_on_empty_string() {
+        match EString::new().parse::<NonEmptySepVec<&str, ','>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::new());
+                assert_eq!(reason, Reason::TooFew { min: 1, actual: 0 });
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_vec() {
+        let vec = Workers::from(vec![1, 2]);
+        assert_eq!(vec.to_estring(), EString::from("1,2"));
+    }
+}
@@ -0,0 +1,139 @@
+//! Contains the implementations to bracketed type
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+
+/// Wrapper that requires and strips the opening (`OPEN`) and closing (`CLOSE`) delimiters
+/// before continue.
+///
+/// The delimiters must enclose the whole fragment, so `[1],[2]` is not a bracketed fragment.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{Bracketed, EString, SepVec};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("[1,2,3]").parse::<Bracketed<SepVec<i32, ','>, '[', ']'>>()?;
+///     assert_eq!(res.as_slice(), [1, 2, 3]);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Bracketed<T, const OPEN: char, const CLOSE: char>(pub T);
+
+impl<T, const OPEN: char, const CLOSE: char> std::ops::Deref for Bracketed<T, OPEN, CLOSE> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const OPEN: char, const CLOSE: char> std::fmt::Display for Bracketed<T, OPEN, CLOSE>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", OPEN, self.0, CLOSE)
+    }
+}
+
+impl<T, const OPEN: char, const CLOSE: char> ToEString for Bracketed<T, OPEN, CLOSE>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        EString(format!("{}{}{}", OPEN, self.0.to_estring(), CLOSE))
+    }
+}
+
+impl<T, const OPEN: char, const CLOSE: char> ParseFragment for Bracketed<T, OPEN, CLOSE>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let inner = value
+            .strip_prefix(OPEN)
+            .and_then(|rest| rest.strip_suffix(CLOSE))
+            .filter(|inner| is_balanced::<OPEN, CLOSE>(inner))
+            .map(EString::from);
+        match inner {
+            Some(inner) => T::parse_frag(inner).map(Self),
+            None => Err(Error(value, Reason::Split)),
+        }
+    }
+}
+
+/// Checks that the delimiters stripped from the fragment enclose each other.
+fn is_balanced<const OPEN: char, const CLOSE: char>(value: &str) -> bool {
+    if OPEN == CLOSE {
+        return true;
+    }
+
+    let mut depth = 0_usize;
+    for c in value.chars() {
+        if c == OPEN {
+            depth += 1;
+        } else if c == CLOSE {
+            match depth.checked_sub(1) {
+                Some(d) => depth = d,
+                None => return false,
+            }
+        }
+    }
+    depth == 0
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const OPEN: char, const CLOSE: char> crate::core::Aggregatable for Bracketed<T, OPEN, CLOSE>
+where
+    T: crate::core::Aggregatable,
+{
+    type Item = T::Item;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0.items()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Square<T> = Bracketed<T, '[', ']'>;
+
+    #[test]
+    fn should_strip_brackets() {
+        let estr = EString::from("[hello]");
+        match estr.parse::<Square<&str>>() {
+            Ok(res) => assert_eq!(*res, "hello"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error_without_brackets() {
+        for input in ["hello", "[hello", "hello]", "[1],[2]"] {
+            let estr = EString::from(input);
+            match estr.parse::<Square<&str>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Split);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_bracketed() {
+        let res = Bracketed::<_, '(', ')'>(Bracketed::<_, '[', ']'>(1));
+        assert_eq!(res.to_estring(), EString::from("([1])"));
+    }
+}
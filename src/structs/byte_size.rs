@@ -0,0 +1,267 @@
+//! Contains the implementations to byte size type
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::std::number::{scale_decimal, split_decimal};
+use crate::{Error, Reason};
+
+/// Prefixes of units in ascending order, starting from kilo.
+const PREFIXES: [char; 6] = ['k', 'm', 'g', 't', 'p', 'e'];
+
+/// Wrapper for number of bytes to parse a size with an optional unit, e.g. `512MiB`, `2GB` or
+/// `64k`.
+///
+/// Units are case-insensitive. SI units (`k`, `kB`, `M`, `MB`, ... up to `E`) are powers of
+/// 1000, IEC units (`Ki`, `KiB`, `Mi`, `MiB`, ...) are powers of 1024, and `B` or no unit means
+/// bytes. Fractional values are truncated to whole bytes. Returns ``Reason::Overflow`` if the
+/// size doesn't fit into `u64`.
+///
+/// The size is formatted with the largest unit that represents it exactly.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{ByteSize, EString, ToEString};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("512MiB").parse::<ByteSize>()?;
+///     assert_eq!(*res, 512 * 1024 * 1024);
+///     assert_eq!(res.to_estring(), EString::from("512MiB"));
+///
+///     let res = EString::from("1.5 kb").parse::<ByteSize>()?;
+///     assert_eq!(*res, 1500);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct ByteSize(pub u64);
+
+impl std::ops::Deref for ByteSize {
+    type Target = u64;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    #[inline]
+    fn from(bytes: u64) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Returns the multiplier of the unit, or `None` if the unit is unknown.
+fn multiplier(unit: &str) -> Option<u128> {
+    let unit = unit.to_lowercase();
+    let unit = unit.strip_suffix('b').unwrap_or(&unit);
+    if unit.is_empty() {
+        return Some(1);
+    }
+
+    let (prefix, base) = match unit.strip_suffix('i') {
+        Some(prefix) => (prefix, 1024u128),
+        None => (unit, 1000u128),
+    };
+    let mut chars = prefix.chars();
+    let exp = match (chars.next(), chars.next()) {
+        (Some(c), None) => PREFIXES.iter().position(|&p| p == c)?,
+        _ => return None,
+    };
+    Some(base.pow(u32::try_from(exp).ok()? + 1))
+}
+
+impl std::fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bytes = u128::from(self.0);
+        let unit = PREFIXES
+            .iter()
+            .zip(1u32..7)
+            .rev()
+            .flat_map(|(&prefix, exp)| {
+                let si_prefix = if prefix == 'k' {
+                    prefix
+                } else {
+                    prefix.to_ascii_uppercase()
+                };
+                [
+                    (
+                        1024u128.pow(exp),
+                        format!("{}iB", prefix.to_ascii_uppercase()),
+                    ),
+                    (1000u128.pow(exp), format!("{si_prefix}B")),
+                ]
+            })
+            .find(|(mul, _)| bytes != 0 && bytes % mul == 0);
+        match unit {
+            Some((mul, unit)) => write!(f, "{}{unit}", bytes / mul),
+            None => write!(f, "{bytes}B"),
+        }
+    }
+}
+
+impl ToEString for ByteSize {
+    fn to_estring(&self) -> EString {
+        EString(self.to_string())
+    }
+}
+
+impl ParseFragment for ByteSize {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let (int, frac, mul) = match split_decimal(value.trim()) {
+            Some((int, frac, unit)) => match multiplier(unit.trim_start()) {
+                Some(mul) => (int, frac, mul),
+                None => return Err(Error(value, Reason::Parse)),
+            },
+            None => return Err(Error(value, Reason::Parse)),
+        };
+
+        scale_decimal(int, frac, mul)
+            .and_then(|bytes| u64::try_from(bytes).ok())
+            .map(Self)
+            .ok_or(Error(value, Reason::Overflow))
+    }
+}
+
+impl std::iter::Sum for ByteSize {
+    /// # Panics
+    ///
+    /// Panics if the total size doesn't fit into `u64`.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.fold(0, |acc, size| {
+            acc.checked_add(size.0)
+                .expect("attempt to sum byte sizes with overflow")
+        }))
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl crate::core::Aggregatable for ByteSize {
+    type Item = Self;
+
+    #[inline]
+    fn items(self) -> Vec<Self::Item> {
+        vec![self]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_byte_size() {
+        let test_cases = [
+            ("0", 0),
+            ("100", 100),
+            ("100B", 100),
+            ("64k", 64_000),
+            ("2GB", 2_000_000_000),
+            ("2gb", 2_000_000_000),
+            ("512MiB", 512 << 20),
+            ("512mib", 512 << 20),
+            ("4Ki", 4096),
+            (" 1 TiB ", 1 << 40),
+            ("15EiB", 15 << 60),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<ByteSize>() {
+                Ok(res) => assert_eq!(res, ByteSize(expected)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_fractional_byte_size() {
+        let test_cases = [
+            ("1.5kB", 1500),
+            ("0.5KiB", 512),
+            (".25MiB", 256 << 10),
+            ("1.7", 1),
+        ];
+
+        for (input, expected) in test_cases {
+            let estr = EString::from(input);
+            match estr.parse::<ByteSize>() {
+                Ok(res) => assert_eq!(res, ByteSize(expected)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        for input in ["", "MB", "-1MB", "1.2.3MB", "1XB", "1KiBB", "1 2"] {
+            let estr = EString::from(input);
+            match estr.parse::<ByteSize>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Parse);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_overflow_error() {
+        for input in ["16EiB", "18446744073709551616", "20EB"] {
+            let estr = EString::from(input);
+            match estr.parse::<ByteSize>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Overflow);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_byte_size() {
+        let test_cases = [
+            (0, "0B"),
+            (100, "100B"),
+            (1024, "1KiB"),
+            (1000, "1kB"),
+            (2_000_000_000, "2GB"),
+            (512 << 20, "512MiB"),
+            (1536, "1536B"),
+        ];
+
+        for (bytes, expected) in test_cases {
+            let estr = ByteSize(bytes).to_estring();
+            assert_eq!(estr, EString::from(expected));
+            assert_eq!(estr.parse::<ByteSize>(), Ok(ByteSize(bytes)));
+        }
+    }
+
+    #[cfg(feature = "aggs")]
+    #[test]
+    fn should_sum_byte_sizes() {
+        use crate::agg::Sum;
+        use crate::core::Aggregate;
+        use crate::structs::SepVec;
+
+        let estr = EString::from("1GiB + 512MiB");
+        match estr.parse::<Sum<SepVec<ByteSize, '+'>>>() {
+            Ok(res) => assert_eq!(res.agg(), ByteSize(1536 << 20)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to sum byte sizes with overflow")]
+    fn should_panic_on_sum_overflow() {
+        let _ = [ByteSize(u64::MAX), ByteSize(1)]
+            .into_iter()
+            .sum::<ByteSize>();
+    }
+}
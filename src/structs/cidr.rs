@@ -0,0 +1,217 @@
+//! Contains the implementations to CIDR block type
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::net::IpAddr;
+
+/// Wrapper for CIDR block to parse an IP address and a network prefix length separated by `/`,
+/// e.g. `10.0.0.0/8` or `fd00::/8`.
+///
+/// An address without a prefix length is parsed as a block of a single address. Returns
+/// ``Reason::Prefix`` if the prefix length is greater than the number of address bits.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{Cidr, EString, SepVec};
+///
+/// fn main() -> estring::Result<()> {
+///     let allowlist = EString::from("10.0.0.0/8, 192.168.1.1, ::1")
+///         .parse::<SepVec<Cidr, ','>>()?;
+///
+///     let allowed = |ip: &str| allowlist.iter().any(|c| c.contains(&ip.parse().unwrap()));
+///     assert!(allowed("10.20.30.40"));
+///     assert!(allowed("192.168.1.1"));
+///     assert!(allowed("::1"));
+///     assert!(!allowed("192.168.1.2"));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Cidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    /// Creates a new CIDR block, or returns `None` if the prefix length is greater than the
+    /// number of address bits.
+    #[must_use]
+    pub fn new(addr: IpAddr, prefix: u8) -> Option<Self> {
+        (prefix <= max_prefix(&addr)).then(|| Self { addr, prefix })
+    }
+
+    /// Returns the address as it was written.
+    #[must_use]
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the network prefix length.
+    #[must_use]
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// Returns the first address of the block.
+    #[must_use]
+    pub fn network(&self) -> IpAddr {
+        match self.addr {
+            IpAddr::V4(addr) => IpAddr::V4((u32::from(addr) & mask_v4(self.prefix)).into()),
+            IpAddr::V6(addr) => IpAddr::V6((u128::from(addr) & mask_v6(self.prefix)).into()),
+        }
+    }
+
+    /// Returns `true` if the block contains the address. Addresses of the other family are
+    /// never contained.
+    #[must_use]
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.addr, ip) {
+            (IpAddr::V4(addr), IpAddr::V4(ip)) => {
+                let mask = mask_v4(self.prefix);
+                u32::from(addr) & mask == u32::from(*ip) & mask
+            }
+            (IpAddr::V6(addr), IpAddr::V6(ip)) => {
+                let mask = mask_v6(self.prefix);
+                u128::from(addr) & mask == u128::from(*ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+fn max_prefix(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn mask_v4(prefix: u8) -> u32 {
+    u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0)
+}
+
+fn mask_v6(prefix: u8) -> u128 {
+    u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0)
+}
+
+impl std::fmt::Display for Cidr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+impl ToEString for Cidr {
+    fn to_estring(&self) -> EString {
+        EString(self.to_string())
+    }
+}
+
+impl ParseFragment for Cidr {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let (addr, prefix) = match value.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (value.as_str(), None),
+        };
+
+        let addr = addr
+            .parse::<IpAddr>()
+            .map_err(|_| Error(EString::from(addr), Reason::Parse))?;
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .map_err(|_| Error(EString::from(prefix), Reason::Parse))?,
+            None => max_prefix(&addr),
+        };
+
+        Self::new(addr, prefix).ok_or_else(|| Error(value.clone(), Reason::Prefix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn should_parse_cidr() {
+        let estr = EString::from("10.0.0.0/8");
+        match estr.parse::<Cidr>() {
+            Ok(res) => {
+                assert_eq!(res.addr(), IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)));
+                assert_eq!(res.prefix(), 8);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_single_address_as_cidr() {
+        let estr = EString::from("::1");
+        match estr.parse::<Cidr>() {
+            Ok(res) => assert_eq!(
+                res,
+                Cidr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 128).unwrap()
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_check_containment() {
+        let cidr = EString::from("192.168.1.77/24").parse::<Cidr>().unwrap();
+        assert_eq!(cidr.network(), ip("192.168.1.0"));
+        assert!(cidr.contains(&ip("192.168.1.0")));
+        assert!(cidr.contains(&ip("192.168.1.255")));
+        assert!(!cidr.contains(&ip("192.168.2.1")));
+        assert!(!cidr.contains(&ip("::ffff:192.168.1.1")));
+
+        let any = EString::from("0.0.0.0/0").parse::<Cidr>().unwrap();
+        assert!(any.contains(&ip("255.255.255.255")));
+
+        let cidr = EString::from("fd00::/8").parse::<Cidr>().unwrap();
+        assert!(cidr.contains(&ip("fdab::1")));
+        assert!(!cidr.contains(&ip("fe80::1")));
+    }
+
+    #[test]
+    fn should_throw_prefix_error() {
+        let estr = EString::from("10.0.0.0/33");
+        match estr.parse::<Cidr>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("10.0.0.0/33"));
+                assert_eq!(reason, Reason::Prefix);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        for (input, frag) in [("10.0.0/8", "10.0.0"), ("10.0.0.0/x", "x")] {
+            let estr = EString::from(input);
+            match estr.parse::<Cidr>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(frag));
+                    assert_eq!(reason, Reason::Parse);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_cidr() {
+        let cidr = Cidr::new(ip("fd00::"), 8).unwrap();
+        assert_eq!(cidr.to_estring(), EString::from("fd00::/8"));
+        assert_eq!(cidr.to_estring().parse::<Cidr>(), Ok(cidr));
+    }
+}
@@ -0,0 +1,267 @@
+//! Contains the storages and duplicate policies for ``SepMap`` and ``SepSet``
+//!
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+/// The policy for duplicate keys or elements.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DuplicatePolicy {
+    /// Fail with ``Reason::Duplicate`` on the first duplicate.
+    Reject,
+    /// Keep the first occurrence and ignore the others.
+    FirstWins,
+    /// Replace the previous occurrence with the last one.
+    LastWins,
+}
+
+/// Describes what ``SepMap`` and ``SepSet`` do with duplicates.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+pub trait Duplicates {
+    /// The policy for duplicate keys or elements.
+    const POLICY: DuplicatePolicy;
+}
+
+/// Fail with ``Reason::Duplicate`` on the first duplicate.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RejectDuplicates;
+
+impl Duplicates for RejectDuplicates {
+    const POLICY: DuplicatePolicy = DuplicatePolicy::Reject;
+}
+
+/// Keep the first occurrence and ignore the others.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FirstWins;
+
+impl Duplicates for FirstWins {
+    const POLICY: DuplicatePolicy = DuplicatePolicy::FirstWins;
+}
+
+/// Replace the previous occurrence with the last one.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LastWins;
+
+impl Duplicates for LastWins {
+    const POLICY: DuplicatePolicy = DuplicatePolicy::LastWins;
+}
+
+/// Storage for ``SepMap``.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+pub trait MapStorage<K, V>: Default {
+    /// Whether the entries have a meaningful order (sorted or insertion order). Otherwise,
+    /// entries are sorted by their formatted value on output.
+    const ORDERED: bool;
+
+    /// Returns `true` if the storage contains a value for the specified key.
+    fn contains_key(&self, key: &K) -> bool;
+
+    /// Inserts a key-value pair and replaces the previous value of the key.
+    fn insert(&mut self, key: K, value: V);
+
+    /// Returns all entries of the storage.
+    fn entries(&self) -> Vec<(&K, &V)>;
+}
+
+impl<K, V, H> MapStorage<K, V> for HashMap<K, V, H>
+where
+    K: Hash + Eq,
+    H: BuildHasher + Default,
+{
+    const ORDERED: bool = false;
+
+    fn contains_key(&self, key: &K) -> bool {
+        HashMap::contains_key(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
+    }
+}
+
+impl<K, V> MapStorage<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    const ORDERED: bool = true;
+
+    fn contains_key(&self, key: &K) -> bool {
+        BTreeMap::contains_key(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        BTreeMap::insert(self, key, value);
+    }
+
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
+    }
+}
+
+/// Map that keeps entries in insertion order.
+///
+/// Replacing the value of an existing key keeps the key in its original position.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, OrderedMap, SepMap};
+///
+/// type Env = SepMap<&'static str, ',', '=', i32, OrderedMap<&'static str, i32>>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("b=1,a=2").parse::<Env>()?;
+///     assert_eq!(res.get(&"a"), Some(&2));
+///     assert_eq!(res.to_vec(), vec![("b", 1), ("a", 2)]);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OrderedMap<K, V>(Vec<(K, V)>);
+
+impl<K, V> OrderedMap<K, V>
+where
+    K: PartialEq,
+{
+    /// Returns a reference to the value corresponding to the key.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Removes a key from the map, returning the value of the key if it was in the map.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let i = self.0.iter().position(|(k, _)| k == key)?;
+        Some(self.0.remove(i).1)
+    }
+}
+
+impl<K, V> Default for OrderedMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<K, V> std::ops::Deref for OrderedMap<K, V> {
+    type Target = [(K, V)];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> MapStorage<K, V> for OrderedMap<K, V>
+where
+    K: PartialEq,
+{
+    const ORDERED: bool = true;
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((key, value)),
+        }
+    }
+
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.0.iter().map(|(k, v)| (k, v)).collect()
+    }
+}
+
+/// Storage for ``SepSet``.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+pub trait SetStorage<T>: Default {
+    /// Whether the elements have a meaningful order. Otherwise, elements are sorted by their
+    /// formatted value on output.
+    const ORDERED: bool;
+
+    /// Returns `true` if the storage contains the element.
+    fn contains(&self, elem: &T) -> bool;
+
+    /// Inserts an element and replaces the previous equal element.
+    fn insert(&mut self, elem: T);
+
+    /// Returns all elements of the storage.
+    fn elements(&self) -> Vec<&T>;
+}
+
+impl<T, H> SetStorage<T> for HashSet<T, H>
+where
+    T: Hash + Eq,
+    H: BuildHasher + Default,
+{
+    const ORDERED: bool = false;
+
+    fn contains(&self, elem: &T) -> bool {
+        HashSet::contains(self, elem)
+    }
+
+    fn insert(&mut self, elem: T) {
+        self.replace(elem);
+    }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> SetStorage<T> for BTreeSet<T>
+where
+    T: Ord,
+{
+    const ORDERED: bool = true;
+
+    fn contains(&self, elem: &T) -> bool {
+        BTreeSet::contains(self, elem)
+    }
+
+    fn insert(&mut self, elem: T) {
+        self.replace(elem);
+    }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_insertion_order() {
+        let mut map = OrderedMap::default();
+        MapStorage::insert(&mut map, "b", 1);
+        MapStorage::insert(&mut map, "a", 2);
+        MapStorage::insert(&mut map, "b", 3);
+        assert_eq!(*map, [("b", 3), ("a", 2)]);
+        assert_eq!(map.get(&"a"), Some(&2));
+        assert_eq!(map.get(&"c"), None);
+        assert_eq!(map.remove(&"b"), Some(3));
+        assert_eq!(*map, [("a", 2)]);
+    }
+}
@@ -0,0 +1,214 @@
+//! Contains the implementations to length-prefixed vec type
+//!
+
+use super::split::{self, EmptyInputAsEmptyVec, Plain};
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for ``Vec`` with the declared number of elements before a separator (`SEP_COUNT`)
+/// and the elements split by a separator (`SEP_ITEMS`), e.g. `3:a,b,c`.
+///
+/// Returns ``Reason::Length`` if the number of elements is not equal to the declared count.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{Counted, EString, ToEString};
+///
+/// type Names = Counted<&'static str, ':', ','>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("3:a,b,c").parse::<Names>()?;
+///     assert_eq!(*res, vec!["a", "b", "c"]);
+///     assert_eq!(res.to_estring(), EString::from("3:a,b,c"));
+///
+///     assert!(EString::from("2:a,b,c").parse::<Names>().is_err());
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Counted<T, const SEP_COUNT: char, const SEP_ITEMS: char>(pub Vec<T>);
+
+impl<T, const SEP_COUNT: char, const SEP_ITEMS: char> std::ops::Deref
+    for Counted<T, SEP_COUNT, SEP_ITEMS>
+{
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const SEP_COUNT: char, const SEP_ITEMS: char> From<Vec<T>>
+    for Counted<T, SEP_COUNT, SEP_ITEMS>
+{
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        Self(vec)
+    }
+}
+
+impl<T, const SEP_COUNT: char, const SEP_ITEMS: char> std::fmt::Display
+    for Counted<T, SEP_COUNT, SEP_ITEMS>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{SEP_COUNT}", self.0.len())?;
+        self.0.iter().enumerate().try_for_each(|(i, part)| {
+            if i != 0 {
+                f.write_char(SEP_ITEMS)?;
+            }
+
+            write!(f, "{part}")
+        })
+    }
+}
+
+impl<T, const SEP_COUNT: char, const SEP_ITEMS: char> ToEString for Counted<T, SEP_COUNT, SEP_ITEMS>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        self.0
+            .iter()
+            .enumerate()
+            .try_fold(
+                format!("{}{SEP_COUNT}", self.0.len()),
+                |mut res, (i, part)| {
+                    if i != 0 {
+                        res.write_char(SEP_ITEMS).ok()?;
+                    }
+
+                    write!(res, "{}", part.to_estring()).ok()?;
+                    Some(res)
+                },
+            )
+            .map(EString)
+            .expect("Cannot format Counted ${self.0} to EString")
+    }
+}
+
+impl<T, const SEP_COUNT: char, const SEP_ITEMS: char> ParseFragment
+    for Counted<T, SEP_COUNT, SEP_ITEMS>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let (count, items) = split::split_once::<Plain>(&value, SEP_COUNT)
+            .ok_or_else(|| Error(value.clone(), Reason::Split))?;
+        let count = count.trim();
+        let expected = count
+            .parse::<usize>()
+            .map_err(|_| Error(EString::from(count), Reason::Parse))?;
+
+        let items = EString::from(items);
+        let inner = split::elements::<EmptyInputAsEmptyVec>(&items, SEP_ITEMS)?;
+        if inner.len() != expected {
+            let reason = Reason::Length {
+                expected,
+                actual: inner.len(),
+            };
+            return Err(Error(value, reason));
+        }
+
+        inner
+            .into_iter()
+            .map(T::parse_frag)
+            .collect::<crate::Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const SEP_COUNT: char, const SEP_ITEMS: char> crate::core::Aggregatable
+    for Counted<T, SEP_COUNT, SEP_ITEMS>
+where
+    T: crate::core::Aggregatable,
+{
+    type Item = T::Item;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0.into_iter().flat_map(T::items).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Nums = Counted<i32, ':', ','>;
+
+    #[test]
+    fn should_parse_declared_number_of_items() {
+        let estr = EString::from("3: 1, 2, 3");
+        match estr.parse::<Nums>() {
+            Ok(res) => assert_eq!(*res, vec![1, 2, 3]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_zero_items() {
+        let estr = EString::from("0:");
+        match estr.parse::<Nums>() {
+            Ok(res) => assert_eq!(*res, vec![]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_length_error() {
+        for (input, actual) in [("3:1,2", 2), ("3:1,2,3,4", 4), ("3:", 0)] {
+            let estr = EString::from(input);
+            match estr.parse::<Nums>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(
+                        reason,
+                        Reason::Length {
+                            expected: 3,
+                            actual
+                        }
+                    );
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error_on_invalid_count() {
+        let estr = EString::from("three:1,2,3");
+        match estr.parse::<Nums>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("three"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error_without_count() {
+        let estr = EString::from("1,2,3");
+        match estr.parse::<Nums>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("1,2,3"));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_counted() {
+        assert_eq!(Nums::from(vec![1, 2]).to_estring(), EString::from("2:1,2"));
+        assert_eq!(Nums::from(vec![]).to_estring(), EString::from("0:"));
+    }
+}
@@ -0,0 +1,147 @@
+//! Contains the implementations to inclusive range type with a dash separator
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::std::range::{check_bounds, parse_bound};
+use crate::{Error, Reason};
+use std::ops::RangeInclusive;
+
+/// Wrapper for ``RangeInclusive`` to split string by a dash, e.g. `8000-8080`.
+///
+/// The leading dash belongs to the start, so negative starts like `-10-10` are allowed.
+/// Returns ``Reason::Range`` if start of the range is greater than its end.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{DashRange, EString};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("8000-8080").parse::<DashRange<u16>>()?;
+///     assert_eq!(*res, 8000..=8080);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DashRange<T>(pub RangeInclusive<T>);
+
+impl<T> std::ops::Deref for DashRange<T> {
+    type Target = RangeInclusive<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for DashRange<T> {
+    #[inline]
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self(range)
+    }
+}
+
+impl<T> std::fmt::Display for DashRange<T>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.0.start(), self.0.end())
+    }
+}
+
+impl<T> ToEString for DashRange<T>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        EString(format!(
+            "{}-{}",
+            self.0.start().to_estring(),
+            self.0.end().to_estring()
+        ))
+    }
+}
+
+impl<T> ParseFragment for DashRange<T>
+where
+    T: ParseFragment + PartialOrd,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let sep = value
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| i);
+        match sep {
+            Some(i) => {
+                let (start, end) = (parse_bound(&value[..i])?, parse_bound(&value[i + 1..])?);
+                check_bounds(value, &start, &end)?;
+                Ok(Self(start..=end))
+            }
+            None => Err(Error(value, Reason::Split)),
+        }
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T> crate::core::Aggregatable for DashRange<T>
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0.collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_dash_range() {
+        let estr = EString::from("-10--1");
+        match estr.parse::<DashRange<i32>>() {
+            Ok(res) => assert_eq!(*res, -10..=-1),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error() {
+        let estr = EString::from("-10");
+        match estr.parse::<DashRange<i32>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("-10"));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_dash_range() {
+        assert_eq!(DashRange::from(1..=5).to_estring(), EString::from("1-5"));
+    }
+
+    #[cfg(feature = "aggs")]
+    #[test]
+    fn should_expand_ranges_into_items() {
+        use crate::agg::Sum;
+        use crate::core::Aggregate;
+        use crate::structs::{Either, SepVec};
+
+        type Ports = SepVec<Either<u16, DashRange<u16>>, ','>;
+
+        let res = EString::from("80,8000-8002")
+            .parse::<Sum<Ports>>()
+            .unwrap()
+            .agg();
+        assert_eq!(res, 80 + 8000 + 8001 + 8002);
+    }
+}
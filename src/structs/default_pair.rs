@@ -0,0 +1,205 @@
+//! Contains the implementations to pair tuple type with a default right side
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Provides a default value for ``DefaultPair``.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::DefaultValue;
+///
+/// struct HttpPort;
+///
+/// impl DefaultValue for HttpPort {
+///     type Value = u16;
+///
+///     fn default_value() -> Self::Value {
+///         80
+///     }
+/// }
+///
+/// assert_eq!(HttpPort::default_value(), 80);
+/// ```
+///
+pub trait DefaultValue {
+    /// The type of the default value.
+    type Value;
+
+    /// Returns the default value.
+    fn default_value() -> Self::Value;
+}
+
+/// Wrapper for pair (A, B) tuple to split string by a separator (`S1`) if the string contains
+/// it, where `B` is the value type of the default (`D`).
+///
+/// If the string doesn't contain the separator, the whole string is parsed as `A` and the right
+/// side is taken from ``DefaultValue::default_value``.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{DefaultPair, DefaultValue, EString};
+///
+/// struct Port8080;
+///
+/// impl DefaultValue for Port8080 {
+///     type Value = u16;
+///
+///     fn default_value() -> Self::Value {
+///         8080
+///     }
+/// }
+///
+/// type HostPort = DefaultPair<&'static str, ':', Port8080>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("localhost").parse::<HostPort>()?;
+///     assert_eq!((res.0, res.1), ("localhost", 8080));
+///     let res = EString::from("localhost:3000").parse::<HostPort>()?;
+///     assert_eq!((res.0, res.1), ("localhost", 3000));
+///     Ok(())
+/// }
+/// ```
+///
+pub struct DefaultPair<A, const S1: char, D: DefaultValue>(pub A, pub D::Value);
+
+impl<A, const S1: char, D> std::fmt::Debug for DefaultPair<A, S1, D>
+where
+    A: std::fmt::Debug,
+    D: DefaultValue,
+    D::Value: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DefaultPair")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
+impl<A, const S1: char, D> PartialEq for DefaultPair<A, S1, D>
+where
+    A: PartialEq,
+    D: DefaultValue,
+    D::Value: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+impl<A, const S1: char, D> Clone for DefaultPair<A, S1, D>
+where
+    A: Clone,
+    D: DefaultValue,
+    D::Value: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<A, const S1: char, D> From<(A, D::Value)> for DefaultPair<A, S1, D>
+where
+    D: DefaultValue,
+{
+    #[inline]
+    fn from((a, b): (A, D::Value)) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<A, const S1: char, D> std::fmt::Display for DefaultPair<A, S1, D>
+where
+    A: std::fmt::Display,
+    D: DefaultValue,
+    D::Value: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.0, S1, self.1)
+    }
+}
+
+impl<A, const S1: char, D> ToEString for DefaultPair<A, S1, D>
+where
+    A: ToEString,
+    D: DefaultValue,
+    D::Value: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        let mut res = String::new();
+        write!(res, "{}{}{}", self.0.to_estring(), S1, self.1.to_estring())
+            .ok()
+            .expect("Cannot parse DefaultPair to EString");
+        EString(res)
+    }
+}
+
+impl<A, const S1: char, D> ParseFragment for DefaultPair<A, S1, D>
+where
+    A: ParseFragment,
+    D: DefaultValue,
+    D::Value: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let (a, b) = match value.split_once(S1) {
+            Some((a, b)) => (EString::from(a), Some(EString::from(b))),
+            None => (value, None),
+        };
+        let a = A::parse_frag(a.clone()).map_err(|_| Error(a, Reason::Parse))?;
+        let b = match b {
+            Some(b) => D::Value::parse_frag(b.clone()).map_err(|_| Error(b, Reason::Parse))?,
+            None => D::default_value(),
+        };
+        Ok(Self(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Port8080;
+
+    impl DefaultValue for Port8080 {
+        type Value = u16;
+
+        fn default_value() -> Self::Value {
+            8080
+        }
+    }
+
+    type HostPort = DefaultPair<&'static str, ':', Port8080>;
+
+    #[test]
+    fn should_parse_pair_with_default_value() {
+        let estr = EString::from("localhost");
+        match estr.parse::<HostPort>() {
+            Ok(res) => assert_eq!(res, DefaultPair("localhost", 8080)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_pair_with_separator() {
+        let estr = EString::from("localhost:3000");
+        match estr.parse::<HostPort>() {
+            Ok(res) => assert_eq!(res, DefaultPair("localhost", 3000)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_pair_with_default_value() {
+        let pair = EString::from("localhost").parse::<HostPort>().unwrap();
+        assert_eq!(pair.to_estring(), EString::from("localhost:8080"));
+    }
+}
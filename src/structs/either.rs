@@ -0,0 +1,158 @@
+//! Contains the implementations to alternation types
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+
+/// Wrapper that tries to parse string as `A`, and as `B` if it fails.
+///
+/// If none of the alternatives matches, returns ``Reason::NoMatch`` with errors of all
+/// alternatives.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Either, Pair};
+///
+/// type Limit = Either<u32, Pair<u32, '-', u32>>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("10").parse::<Limit>()?;
+///     assert_eq!(res, Either::Left(10));
+///     let res = EString::from("1-10").parse::<Limit>()?;
+///     assert_eq!(res, Either::Right(Pair(1, 10)));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Either<A, B> {
+    /// The first alternative
+    Left(A),
+    /// The second alternative
+    Right(B),
+}
+
+impl<A, B> std::fmt::Display for Either<A, B>
+where
+    A: std::fmt::Display,
+    B: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Left(a) => a.fmt(f),
+            Self::Right(b) => b.fmt(f),
+        }
+    }
+}
+
+impl<A, B> ToEString for Either<A, B>
+where
+    A: ToEString,
+    B: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        match self {
+            Self::Left(a) => a.to_estring(),
+            Self::Right(b) => b.to_estring(),
+        }
+    }
+}
+
+impl<A, B> ParseFragment for Either<A, B>
+where
+    A: ParseFragment,
+    B: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let left = match A::parse_frag(value.clone()) {
+            Ok(a) => return Ok(Self::Left(a)),
+            Err(err) => err,
+        };
+        let right = match B::parse_frag(value.clone()) {
+            Ok(b) => return Ok(Self::Right(b)),
+            Err(err) => err,
+        };
+        Err(Error(value, Reason::NoMatch(vec![left, right])))
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<R, A, B> crate::core::Aggregatable for Either<A, B>
+where
+    A: crate::core::Aggregatable<Item = R>,
+    B: crate::core::Aggregatable<Item = R>,
+{
+    type Item = R;
+
+    fn items(self) -> Vec<Self::Item> {
+        match self {
+            Self::Left(a) => a.items(),
+            Self::Right(b) => b.items(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::Pair;
+
+    type Limit = Either<u32, Pair<u32, '-', u32>>;
+
+    #[test]
+    fn should_parse_first_alternative() {
+        let estr = EString::from("10");
+        match estr.parse::<Limit>() {
+            Ok(res) => assert_eq!(res, Either::Left(10)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_second_alternative() {
+        let estr = EString::from("1-10");
+        match estr.parse::<Limit>() {
+            Ok(res) => assert_eq!(res, Either::Right(Pair(1, 10))),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_prefer_first_matching_alternative() {
+        let estr = EString::from("10");
+        match estr.parse::<Either<&str, u32>>() {
+            Ok(res) => assert_eq!(res, Either::Left("10")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_error_with_all_alternatives() {
+        let estr = EString::from("a");
+        match estr.parse::<Limit>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("a"));
+                assert_eq!(
+                    reason,
+                    Reason::NoMatch(vec![
+                        Error(EString::from("a"), Reason::Parse),
+                        Error(EString::from("a"), Reason::Split),
+                    ])
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_held_alternative() {
+        assert_eq!(Limit::Left(10).to_estring(), EString::from("10"));
+        assert_eq!(
+            Limit::Right(Pair(1, 10)).to_estring(),
+            EString::from("1-10")
+        );
+    }
+}
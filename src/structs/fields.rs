@@ -0,0 +1,199 @@
+//! Contains the implementations to parse key-value lists into named fields
+//!
+
+use super::collection::{OrderedMap, RejectDuplicates};
+use super::SepMap;
+use crate::core::{EString, ParseFragment};
+use crate::{Error, Reason};
+
+/// Builder to parse key-value entries into named fields of a struct.
+///
+/// Entries are split by a separator (`ENTRY_SEP`) and each entry is split into key and value by
+/// a separator (`KV_SEP`), as ``SepMap`` does. Entries can be in any order, and duplicate keys
+/// are rejected with ``Reason::Duplicate``.
+///
+/// Take each field by its key, and then call [finish](Fields::finish) to reject unknown keys
+/// with ``Reason::Unknown``. Don't call it if unknown keys should be ignored.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Fields, ParseFragment};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Settings {
+///     timeout: u32,
+///     retries: u8,
+///     verbose: bool,
+/// }
+///
+/// impl ParseFragment for Settings {
+///     fn parse_frag(es: EString) -> estring::Result<Self> {
+///         let mut fields = Fields::<',', '='>::parse_frag(es)?;
+///         let res = Settings {
+///             timeout: fields.require("timeout")?,
+///             retries: fields.take_or("retries", 3)?,
+///             verbose: fields.take_or_default("verbose")?,
+///         };
+///         fields.finish()?;
+///         Ok(res)
+///     }
+/// }
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("verbose=on,timeout=30").parse::<Settings>()?;
+///     assert_eq!(res, Settings { timeout: 30, retries: 3, verbose: true });
+///
+///     assert!(EString::from("timeout=30,color=on").parse::<Settings>().is_err());
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct Fields<const ENTRY_SEP: char, const KV_SEP: char>(OrderedMap<String, EString>);
+
+impl<const ENTRY_SEP: char, const KV_SEP: char> Fields<ENTRY_SEP, KV_SEP> {
+    /// Removes the key and parses its value, or returns `None` if the key is missing.
+    ///
+    /// # Errors
+    ///
+    /// If the value cannot be parsed, returns ``Reason::Field`` with the key and the reason of
+    /// the failure.
+    pub fn take<T: ParseFragment>(&mut self, key: &str) -> crate::Result<Option<T>> {
+        self.0
+            .remove(&key.to_string())
+            .map(|value| {
+                T::parse_frag(value).map_err(|Error(orig, reason)| {
+                    let reason = Box::new(reason);
+                    let key = key.to_string();
+                    Error(orig, Reason::Field { key, reason })
+                })
+            })
+            .transpose()
+    }
+
+    /// Removes the key and parses its value, or returns `default` if the key is missing.
+    ///
+    /// # Errors
+    ///
+    /// See [take](Fields::take).
+    pub fn take_or<T: ParseFragment>(&mut self, key: &str, default: T) -> crate::Result<T> {
+        self.take(key).map(|value| value.unwrap_or(default))
+    }
+
+    /// Removes the key and parses its value, or returns the default value of `T` if the key is
+    /// missing.
+    ///
+    /// # Errors
+    ///
+    /// See [take](Fields::take).
+    pub fn take_or_default<T: ParseFragment + Default>(&mut self, key: &str) -> crate::Result<T> {
+        self.take(key).map(Option::unwrap_or_default)
+    }
+
+    /// Removes the key and parses its value.
+    ///
+    /// # Errors
+    ///
+    /// If the key is missing, returns ``Reason::Missing`` with the key. See also
+    /// [take](Fields::take).
+    pub fn require<T: ParseFragment>(&mut self, key: &str) -> crate::Result<T> {
+        self.take(key)?
+            .ok_or_else(|| Error(EString::from(key), Reason::Missing))
+    }
+
+    /// Checks that all keys were taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns ``Reason::Unknown`` with the first key that was not taken.
+    pub fn finish(self) -> crate::Result<()> {
+        match self.0.first() {
+            Some((key, _)) => Err(Error(EString::from(key.as_str()), Reason::Unknown)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<const ENTRY_SEP: char, const KV_SEP: char> ParseFragment for Fields<ENTRY_SEP, KV_SEP> {
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        SepMap::<String, ENTRY_SEP, KV_SEP, EString, OrderedMap<_, _>, RejectDuplicates>::parse_frag(
+            value,
+        )
+        .map(|map| Self(map.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CommaFields = Fields<',', '='>;
+
+    #[test]
+    fn should_take_fields_in_any_order() {
+        let estr = EString::from("retries=3, timeout=30");
+        let mut fields = estr.parse::<CommaFields>().unwrap();
+        assert_eq!(fields.take::<u32>("timeout"), Ok(Some(30)));
+        assert_eq!(fields.take::<u8>("retries"), Ok(Some(3)));
+        assert_eq!(fields.take::<u8>("retries"), Ok(None));
+        assert_eq!(fields.finish(), Ok(()));
+    }
+
+    #[test]
+    fn should_use_defaults_for_missing_keys() {
+        let mut fields = EString::new().parse::<CommaFields>().unwrap();
+        assert_eq!(fields.take_or("timeout", 30), Ok(30));
+        assert_eq!(fields.take_or_default::<bool>("verbose"), Ok(false));
+    }
+
+    #[test]
+    fn should_throw_missing_error() {
+        let mut fields = EString::new().parse::<CommaFields>().unwrap();
+        assert_eq!(
+            fields.require::<u32>("timeout"),
+            Err(Error(EString::from("timeout"), Reason::Missing))
+        );
+    }
+
+    #[test]
+    fn should_throw_field_error() {
+        let estr = EString::from("timeout=soon");
+        let mut fields = estr.parse::<CommaFields>().unwrap();
+        assert_eq!(
+            fields.take::<u32>("timeout"),
+            Err(Error(
+                EString::from("soon"),
+                Reason::Field {
+                    key: String::from("timeout"),
+                    reason: Box::new(Reason::Parse)
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn should_throw_unknown_error() {
+        let estr = EString::from("timeout=30,color=on");
+        let mut fields = estr.parse::<CommaFields>().unwrap();
+        assert_eq!(fields.take::<u32>("timeout"), Ok(Some(30)));
+        assert_eq!(
+            fields.finish(),
+            Err(Error(EString::from("color"), Reason::Unknown))
+        );
+    }
+
+    #[test]
+    fn should_throw_duplicate_error() {
+        let estr = EString::from("timeout=30,timeout=40");
+        match estr.parse::<CommaFields>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("timeout=40"));
+                assert_eq!(reason, Reason::Duplicate);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Contains the implementations to line-oriented vec type
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for ``Vec`` to split string into lines.
+///
+/// * Both `\n` and `\r\n` line endings are supported.
+/// * Blank lines and lines starting with the comment prefix (`COMMENT`, `#` by default) are
+///   skipped.
+/// * A line ending with a backslash is joined with the next line.
+/// * Each line is trimmed before parsing.
+///
+/// If a line cannot be parsed, returns ``Reason::Line`` with the original line number and the
+/// reason of the failure.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Lines, Pair};
+///
+/// const CONFIG: &str = "
+/// ## database
+/// DATABASE_URL=postgres://localhost:5432/db\r
+///
+/// HOSTS=a,\\
+///       b
+/// ";
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from(CONFIG).parse::<Lines<Pair<&str, '=', &str>>>()?;
+///     assert_eq!(
+///         *res,
+///         vec![
+///             Pair("DATABASE_URL", "postgres://localhost:5432/db"),
+///             Pair("HOSTS", "a,      b"),
+///         ]
+///     );
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lines<T, const COMMENT: char = '#'>(pub Vec<T>);
+
+impl<T, const COMMENT: char> std::ops::Deref for Lines<T, COMMENT> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const COMMENT: char> From<Vec<T>> for Lines<T, COMMENT> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        Self(vec)
+    }
+}
+
+impl<T, const COMMENT: char> std::fmt::Display for Lines<T, COMMENT>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|line| writeln!(f, "{line}"))
+    }
+}
+
+impl<T, const COMMENT: char> ToEString for Lines<T, COMMENT>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        self.0
+            .iter()
+            .try_fold(String::new(), |mut res, line| {
+                writeln!(res, "{}", line.to_estring()).ok()?;
+                Some(res)
+            })
+            .map(EString)
+            .expect("Cannot format Lines ${self.0} to EString")
+    }
+}
+
+impl<T, const COMMENT: char> ParseFragment for Lines<T, COMMENT>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        logical_lines::<COMMENT>(&value)
+            .into_iter()
+            .map(|(line, content)| {
+                T::parse_frag(EString(content)).map_err(|Error(orig, reason)| {
+                    let reason = Box::new(reason);
+                    Error(orig, Reason::Line { line, reason })
+                })
+            })
+            .collect::<crate::Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+/// Returns trimmed logical lines with the numbers of their first physical lines.
+fn logical_lines<const COMMENT: char>(value: &str) -> Vec<(usize, String)> {
+    let mut res = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for (i, line) in value.split('\n').enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        let (number, mut content) = match current.take() {
+            Some(continued) => continued,
+            None if line.trim().is_empty() || line.trim_start().starts_with(COMMENT) => continue,
+            None => (i + 1, String::new()),
+        };
+
+        if let Some(part) = line.trim_end().strip_suffix('\\') {
+            content.push_str(part);
+            current = Some((number, content));
+        } else {
+            content.push_str(line);
+            res.push((number, content.trim().to_string()));
+        }
+    }
+
+    if let Some((number, content)) = current {
+        res.push((number, content.trim().to_string()));
+    }
+
+    res
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const COMMENT: char> crate::core::Aggregatable for Lines<T, COMMENT>
+where
+    T: crate::core::Aggregatable,
+{
+    type Item = T::Item;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0.into_iter().flat_map(T::items).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::Pair;
+
+    type EqPair<A, B> = Pair<A, '=', B>;
+
+    #[test]
+    fn should_parse_crlf_lines() {
+        let estr = EString::from("a=1\r\nb=2\r\n");
+        match estr.parse::<Lines<EqPair<&str, i32>>>() {
+            Ok(res) => assert_eq!(*res, vec![Pair("a", 1), Pair("b", 2)]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_skip_blank_and_comment_lines() {
+        let estr = EString::from("; comment\n\n   \na=1\n  ; indented comment\nb=2");
+        match estr.parse::<Lines<EqPair<&str, i32>, ';'>>() {
+            Ok(res) => assert_eq!(*res, vec![Pair("a", 1), Pair("b", 2)]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_join_continued_lines() {
+        let estr = EString::from("a=1,\\\n  2,\\\r\n3\nb=4\\");
+        match estr.parse::<Lines<EqPair<&str, &str>>>() {
+            Ok(res) => assert_eq!(*res, vec![Pair("a", "1,  2,3"), Pair("b", "4")]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_error_with_line_number() {
+        let estr = EString::from("# comment\na=1\\\n1\n\nb=two");
+        match estr.parse::<Lines<EqPair<&str, i32>>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("two"));
+                assert_eq!(
+                    reason,
+                    Reason::Line {
+                        line: 5,
+                        reason: Box::new(Reason::Parse)
+                    }
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_lines() {
+        let lines = Lines::<_>::from(vec![Pair::<_, '=', _>("a", 1), Pair("b", 2)]);
+        assert_eq!(lines.to_estring(), EString::from("a=1\nb=2\n"));
+    }
+}
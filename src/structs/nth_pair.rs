@@ -0,0 +1,125 @@
+//! Contains the implementations to pair tuple type that splits on the Nth separator
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for pair (A, B) tuple to split string by the `N`th separator (`S1`), starting from
+/// one.
+///
+/// ``NthPair`` with `N` equal to `1` behaves like ``Pair``. `N` equal to `0` fails to compile.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, NthPair};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("a.b.c.d").parse::<NthPair<&str, '.', 2, &str>>()?;
+///     assert_eq!(res, NthPair("a.b", "c.d"));
+///     Ok(())
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use estring::{EString, NthPair};
+///
+/// let _ = EString::from("a.b").parse::<NthPair<&str, '.', 0, &str>>();
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct NthPair<A, const S1: char, const N: usize, B>(pub A, pub B);
+
+impl<A, B, const S1: char, const N: usize> From<(A, B)> for NthPair<A, S1, N, B> {
+    #[inline]
+    fn from((a, b): (A, B)) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<A, B, const S1: char, const N: usize> std::fmt::Display for NthPair<A, S1, N, B>
+where
+    A: std::fmt::Display,
+    B: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.0, S1, self.1)
+    }
+}
+
+impl<A, B, const S1: char, const N: usize> ToEString for NthPair<A, S1, N, B>
+where
+    A: ToEString,
+    B: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        let mut res = String::new();
+        write!(res, "{}{}{}", self.0.to_estring(), S1, self.1.to_estring())
+            .ok()
+            .expect("Cannot parse NthPair to EString");
+        EString(res)
+    }
+}
+
+impl<A, B, const S1: char, const N: usize> NthPair<A, S1, N, B> {
+    /// Fails to compile if `N` is zero, because the occurrences start from one.
+    const N_IS_NOT_ZERO: () = assert!(N > 0, "NthPair requires N to be greater than zero");
+}
+
+impl<A, B, const S1: char, const N: usize> ParseFragment for NthPair<A, S1, N, B>
+where
+    A: ParseFragment,
+    B: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let () = Self::N_IS_NOT_ZERO;
+
+        N.checked_sub(1)
+            .and_then(|n| value.match_indices(S1).nth(n))
+            .map(|(i, _)| (&value[..i], &value[i + S1.len_utf8()..]))
+            .ok_or_else(|| Error(value.clone(), Reason::Split))
+            .and_then(|(a, b)| {
+                let (a, b) = (EString::from(a), EString::from(b));
+                let a = A::parse_frag(a.clone()).map_err(|_| Error(a, Reason::Parse))?;
+                let b = B::parse_frag(b.clone()).map_err(|_| Error(b, Reason::Parse))?;
+                Ok(Self(a, b))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_into_pair() {
+        let estr = EString::from("10.0.0.1.8080");
+        match estr.parse::<NthPair<&str, '.', 4, u16>>() {
+            Ok(res) => assert_eq!(res, NthPair("10.0.0.1", 8080)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error() {
+        for input in ["a.b", "a"] {
+            let estr = EString::from(input);
+            match estr.parse::<NthPair<&str, '.', 2, &str>>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(reason, Reason::Split);
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_format_pair() {
+        let pair = NthPair::<_, '.', 2, _>::from(("a.b", "c"));
+        assert_eq!(pair.to_estring(), EString::from("a.b.c"));
+    }
+}
@@ -0,0 +1,137 @@
+//! Contains the implementations to triple-tuple type that splits on the Nth separators
+//!
+
+use super::NthPair;
+use crate::core::{EString, ParseFragment, ToEString};
+use std::fmt::Write;
+
+/// Wrapper for trio (A, B, C) tuple to split string by the `N1`th separator (`S1`) and the
+/// `N2`th separator (`S2`), starting from one.
+///
+/// The string is split by the `N1`th `S1` first, and then the rest is split by the `N2`th `S2`.
+/// `N1` or `N2` equal to `0` fails to compile.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, NthTrio};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("a.b.c:d:e").parse::<NthTrio<&str, '.', 2, &str, ':', 2, &str>>()?;
+///     assert_eq!(res, NthTrio("a.b", "c:d", "e"));
+///     Ok(())
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use estring::{EString, NthTrio};
+///
+/// let _ = EString::from("a.b.c").parse::<NthTrio<&str, '.', 1, &str, '.', 0, &str>>();
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct NthTrio<A, const S1: char, const N1: usize, B, const S2: char, const N2: usize, C>(
+    pub A,
+    pub B,
+    pub C,
+);
+
+impl<A, B, C, const S1: char, const N1: usize, const S2: char, const N2: usize> From<(A, B, C)>
+    for NthTrio<A, S1, N1, B, S2, N2, C>
+{
+    #[inline]
+    fn from((a, b, c): (A, B, C)) -> Self {
+        Self(a, b, c)
+    }
+}
+
+impl<A, B, C, const S1: char, const N1: usize, const S2: char, const N2: usize> std::fmt::Display
+    for NthTrio<A, S1, N1, B, S2, N2, C>
+where
+    A: std::fmt::Display,
+    B: std::fmt::Display,
+    C: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())?;
+        f.write_char(S1)?;
+        f.write_str(&self.1.to_string())?;
+        f.write_char(S2)?;
+        f.write_str(&self.2.to_string())
+    }
+}
+
+impl<A, B, C, const S1: char, const N1: usize, const S2: char, const N2: usize> ToEString
+    for NthTrio<A, S1, N1, B, S2, N2, C>
+where
+    A: ToEString,
+    B: ToEString,
+    C: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        let mut res = String::new();
+        write!(
+            res,
+            "{}{}{}{}{}",
+            self.0.to_estring(),
+            S1,
+            self.1.to_estring(),
+            S2,
+            self.2.to_estring()
+        )
+        .ok()
+        .expect("Cannot parse NthTrio to EString");
+        EString(res)
+    }
+}
+
+impl<A, B, C, const S1: char, const N1: usize, const S2: char, const N2: usize> ParseFragment
+    for NthTrio<A, S1, N1, B, S2, N2, C>
+where
+    A: ParseFragment,
+    B: ParseFragment,
+    C: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        NthPair::<A, S1, N1, EString>::parse_frag(value).and_then(|NthPair(a, rest)| {
+            NthPair::<B, S2, N2, C>::parse_frag(rest).map(|NthPair(b, c)| Self(a, b, c))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Reason};
+
+    type DotTrio<A, B, C> = NthTrio<A, '.', 2, B, '.', 1, C>;
+
+    #[test]
+    fn should_parse_into_trio() {
+        let estr = EString::from("a.b.c.d.e");
+        match estr.parse::<DotTrio<&str, &str, &str>>() {
+            Ok(res) => assert_eq!(res, NthTrio("a.b", "c", "d.e")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error() {
+        let estr = EString::from("a.b.c");
+        match estr.parse::<DotTrio<&str, &str, &str>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("c"));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_trio() {
+        let trio = NthTrio::<_, '.', 2, _, ':', 1, _>::from(("a.b", "c", "d"));
+        assert_eq!(trio.to_estring(), EString::from("a.b.c:d"));
+    }
+}
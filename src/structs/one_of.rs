@@ -0,0 +1,169 @@
+//! Contains the implementations to alternation types with more than two alternatives
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+
+#[doc(hidden)]
+macro_rules! one_of_impl {
+    ($(#[$meta:meta])* $name:ident { $($(#[$vmeta:meta])* $variant:ident($T:ident)),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Clone)]
+        pub enum $name<$($T),+> {
+            $(
+                $(#[$vmeta])*
+                $variant($T),
+            )+
+        }
+
+        impl<$($T),+> std::fmt::Display for $name<$($T),+>
+        where
+            $($T: std::fmt::Display,)+
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$variant(inner) => inner.fmt(f),)+
+                }
+            }
+        }
+
+        impl<$($T),+> ToEString for $name<$($T),+>
+        where
+            $($T: ToEString,)+
+        {
+            fn to_estring(&self) -> EString {
+                match self {
+                    $(Self::$variant(inner) => inner.to_estring(),)+
+                }
+            }
+        }
+
+        impl<$($T),+> ParseFragment for $name<$($T),+>
+        where
+            $($T: ParseFragment,)+
+        {
+            fn parse_frag(value: EString) -> crate::Result<Self> {
+                let mut errors = Vec::new();
+                $(
+                    match $T::parse_frag(value.clone()) {
+                        Ok(inner) => return Ok(Self::$variant(inner)),
+                        Err(err) => errors.push(err),
+                    }
+                )+
+                Err(Error(value, Reason::NoMatch(errors)))
+            }
+        }
+
+        #[cfg(feature = "aggs")]
+        impl<R, $($T),+> crate::core::Aggregatable for $name<$($T),+>
+        where
+            $($T: crate::core::Aggregatable<Item = R>,)+
+        {
+            type Item = R;
+
+            fn items(self) -> Vec<Self::Item> {
+                match self {
+                    $(Self::$variant(inner) => inner.items(),)+
+                }
+            }
+        }
+    };
+}
+
+one_of_impl! {
+    /// Wrapper that tries to parse string as each of three alternatives in order.
+    ///
+    /// If none of the alternatives matches, returns ``Reason::NoMatch`` with errors of all
+    /// alternatives.
+    ///
+    /// **NOTE**: Required the enabling of the `structs` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use estring::{EString, OneOf3};
+    ///
+    /// type Value = OneOf3<bool, i32, String>;
+    ///
+    /// fn main() -> estring::Result<()> {
+    ///     assert_eq!(EString::from("on").parse::<Value>()?, OneOf3::First(true));
+    ///     assert_eq!(EString::from("10").parse::<Value>()?, OneOf3::Second(10));
+    ///     assert_eq!(EString::from("a").parse::<Value>()?, OneOf3::Third("a".into()));
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    OneOf3 {
+        /// The first alternative
+        First(A),
+        /// The second alternative
+        Second(B),
+        /// The third alternative
+        Third(C),
+    }
+}
+
+one_of_impl! {
+    /// Wrapper that tries to parse string as each of four alternatives in order.
+    ///
+    /// If none of the alternatives matches, returns ``Reason::NoMatch`` with errors of all
+    /// alternatives.
+    ///
+    /// **NOTE**: Required the enabling of the `structs` feature.
+    ///
+    OneOf4 {
+        /// The first alternative
+        First(A),
+        /// The second alternative
+        Second(B),
+        /// The third alternative
+        Third(C),
+        /// The fourth alternative
+        Fourth(D),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::Pair;
+
+    type Value = OneOf4<bool, u32, Pair<u32, '-', u32>, Pair<u32, '+', u32>>;
+
+    #[test]
+    fn should_parse_alternatives_in_order() {
+        let test_cases = [
+            ("on", Value::First(true)),
+            ("1", Value::First(true)),
+            ("10", Value::Second(10)),
+            ("1-2", Value::Third(Pair(1, 2))),
+            ("1+2", Value::Fourth(Pair(1, 2))),
+        ];
+
+        for (input, expected) in test_cases {
+            match EString::from(input).parse::<Value>() {
+                Ok(res) => assert_eq!(res, expected),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_error_with_all_alternatives() {
+        let estr = EString::from("a");
+        match estr.parse::<OneOf3<bool, u32, Pair<u32, '-', u32>>>() {
+            Err(Error(orig, Reason::NoMatch(errors))) => {
+                assert_eq!(orig, EString::from("a"));
+                assert_eq!(errors.len(), 3);
+                assert_eq!(errors[2], Error(EString::from("a"), Reason::Split));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_held_alternative() {
+        assert_eq!(Value::Second(10).to_estring(), EString::from("10"));
+        assert_eq!(Value::Fourth(Pair(1, 2)).to_estring(), EString::from("1+2"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Contains the implementations to pair tuple type with an optional right side
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+
+/// Wrapper for pair (A, Option<B>) tuple to split string by a separator (`S1`) if the string
+/// contains it.
+///
+/// If the string doesn't contain the separator, the whole string is parsed as `A` and the right
+/// side is `None`.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, OptPair};
+///
+/// type HostPort = OptPair<&'static str, ':', u16>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("localhost:5432").parse::<HostPort>()?;
+///     assert_eq!(res, OptPair("localhost", Some(5432)));
+///     let res = EString::from("localhost").parse::<HostPort>()?;
+///     assert_eq!(res, OptPair("localhost", None));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct OptPair<A, const S1: char, B>(pub A, pub Option<B>);
+
+impl<A, B, const S1: char> From<(A, Option<B>)> for OptPair<A, S1, B> {
+    #[inline]
+    fn from((a, b): (A, Option<B>)) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<A, B, const S1: char> std::fmt::Display for OptPair<A, S1, B>
+where
+    A: std::fmt::Display,
+    B: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.1 {
+            Some(b) => write!(f, "{}{}{}", self.0, S1, b),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl<A, B, const S1: char> ToEString for OptPair<A, S1, B>
+where
+    A: ToEString,
+    B: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        let mut res = self.0.to_estring();
+        if let Some(b) = &self.1 {
+            res.0.push(S1);
+            res.0.push_str(&b.to_estring());
+        }
+        res
+    }
+}
+
+impl<A, B, const S1: char> ParseFragment for OptPair<A, S1, B>
+where
+    A: ParseFragment,
+    B: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let (a, b) = match value.split_once(S1) {
+            Some((a, b)) => (EString::from(a), Some(EString::from(b))),
+            None => (value, None),
+        };
+        let a = A::parse_frag(a.clone()).map_err(|_| Error(a, Reason::Parse))?;
+        let b = b
+            .map(|b| B::parse_frag(b.clone()).map_err(|_| Error(b, Reason::Parse)))
+            .transpose()?;
+        Ok(Self(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type HostPort = OptPair<&'static str, ':', u16>;
+
+    #[test]
+    fn should_parse_pair_with_separator() {
+        let estr = EString::from("localhost:8080");
+        match estr.parse::<HostPort>() {
+            Ok(res) => assert_eq!(res, OptPair("localhost", Some(8080))),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_pair_without_separator() {
+        let estr = EString::from("localhost");
+        match estr.parse::<HostPort>() {
+            Ok(res) => assert_eq!(res, OptPair("localhost", None)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        let estr = EString::from("localhost:port");
+        match estr.parse::<HostPort>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("port"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_pair() {
+        assert_eq!(
+            HostPort::from(("localhost", Some(80))).to_estring(),
+            EString::from("localhost:80")
+        );
+        assert_eq!(
+            HostPort::from(("localhost", None)).to_estring(),
+            EString::from("localhost")
+        );
+    }
+}
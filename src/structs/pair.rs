@@ -89,7 +89,7 @@ mod tests {
         match estr.parse::<EqPair<&str, &str>>() {
             Ok(res) => assert_eq!((res.0, res.1), ("hello", "world=hello")),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -98,7 +98,7 @@ mod tests {
         match estr.parse::<EqPair<&str, EqPair<&str, &str>>>() {
             Ok(res) => assert_eq!(res, Pair("hello", Pair("world", "hello"))),
             _ => unreachable!(),
-        };
+        }
     }
 
     type LineVec<T> = SepVec<T, '\n'>;
@@ -112,7 +112,7 @@ hello=bar",
         match estr.parse::<LineVec<EqPair<&str, &str>>>() {
             Ok(res) => assert_eq!(res, SepVec(vec![Pair("foo", "bar"), Pair("hello", "bar"),])),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
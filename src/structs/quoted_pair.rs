@@ -0,0 +1,127 @@
+//! Contains the implementations to pair tuple type that respects quotes
+//!
+
+use super::split::{self, QuoteAware};
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for pair (A, B) tuple to split string by a separator (`S1`) outside of quotes and
+/// backslash escapes.
+///
+/// Both parts are unquoted after splitting, and quoted back when formatting if needed.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, QuotedPair, ToEString};
+///
+/// type EqPair<A, B> = QuotedPair<A, '=', B>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from(r#""a=b"=c"#).parse::<EqPair<String, String>>()?;
+///     assert_eq!(res, QuotedPair(String::from("a=b"), String::from("c")));
+///     assert_eq!(res.to_estring(), EString::from(r#""a=b"=c"#));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct QuotedPair<A, const S1: char, B>(pub A, pub B);
+
+impl<A, B, const S1: char> From<(A, B)> for QuotedPair<A, S1, B> {
+    #[inline]
+    fn from((a, b): (A, B)) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<A, B, const S1: char> std::fmt::Display for QuotedPair<A, S1, B>
+where
+    A: std::fmt::Display,
+    B: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&split::quote::<QuoteAware>(self.0.to_string(), S1))?;
+        f.write_char(S1)?;
+        f.write_str(&split::quote::<QuoteAware>(self.1.to_string(), S1))
+    }
+}
+
+impl<A, B, const S1: char> ToEString for QuotedPair<A, S1, B>
+where
+    A: ToEString,
+    B: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        let mut res = String::new();
+        write!(
+            res,
+            "{}{}{}",
+            split::quote::<QuoteAware>(self.0.to_estring().0, S1),
+            S1,
+            split::quote::<QuoteAware>(self.1.to_estring().0, S1)
+        )
+        .ok()
+        .expect("Cannot parse QuotedPair to EString");
+        EString(res)
+    }
+}
+
+impl<A, B, const S1: char> ParseFragment for QuotedPair<A, S1, B>
+where
+    A: ParseFragment,
+    B: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        split::split_once::<QuoteAware>(&value, S1)
+            .ok_or_else(|| Error(value.clone(), Reason::Split))
+            .and_then(|(a, b)| {
+                let (a, b) = (
+                    split::unquote::<QuoteAware>(a),
+                    split::unquote::<QuoteAware>(b),
+                );
+                let a = A::parse_frag(a.clone()).map_err(|_| Error(a, Reason::Parse))?;
+                let b = B::parse_frag(b.clone()).map_err(|_| Error(b, Reason::Parse))?;
+                Ok(Self(a, b))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type EqPair<A, B> = QuotedPair<A, '=', B>;
+
+    #[test]
+    fn should_parse_into_pair() {
+        let estr = EString::from(r"'a=b'=c\=d");
+        match estr.parse::<EqPair<String, String>>() {
+            Ok(res) => assert_eq!(res, QuotedPair("a=b".into(), "c=d".into())),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error_without_unquoted_separator() {
+        let estr = EString::from(r#""a=b""#);
+        match estr.parse::<EqPair<String, String>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from(r#""a=b""#));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_formatted_pair_back() {
+        let pair = EqPair::from((String::from("a=b"), String::from(r#" "c" "#)));
+        let estr = pair.to_estring();
+        assert_eq!(estr, EString::from(r#""a=b"=" \"c\" ""#));
+        assert_eq!(estr.parse::<EqPair<String, String>>(), Ok(pair));
+    }
+}
@@ -0,0 +1,181 @@
+//! Contains the implementations to fixed repetition type
+//!
+
+use super::split::{self, EmptyInputAsEmptyVec};
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for ``Vec`` to split string by a separator (`SEP`) and require exactly `N` elements.
+///
+/// Unlike ``SepArray``, the elements are stored in a ``Vec``, so the value can be used where a
+/// vector is expected. An empty substring is parsed as zero elements.
+///
+/// Returns ``Reason::Length`` if the number of elements is not equal to `N`.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, Repeat};
+///
+/// type Channels = Repeat<u8, ';', 4>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("1;2;3;4").parse::<Channels>()?;
+///     assert_eq!(*res, vec![1, 2, 3, 4]);
+///     assert!(EString::from("1;2;3").parse::<Channels>().is_err());
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Repeat<T, const SEP: char, const N: usize>(pub Vec<T>);
+
+impl<T, const SEP: char, const N: usize> std::ops::Deref for Repeat<T, SEP, N> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const SEP: char, const N: usize> From<Vec<T>> for Repeat<T, SEP, N> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        Self(vec)
+    }
+}
+
+impl<T, const SEP: char, const N: usize> std::fmt::Display for Repeat<T, SEP, N>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().enumerate().try_for_each(|(i, part)| {
+            if i != 0 {
+                f.write_char(SEP)?;
+            }
+
+            write!(f, "{part}")
+        })
+    }
+}
+
+impl<T, const SEP: char, const N: usize> ToEString for Repeat<T, SEP, N>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        self.0
+            .iter()
+            .enumerate()
+            .try_fold(String::new(), |mut res, (i, part)| {
+                if i != 0 {
+                    res.write_char(SEP).ok()?;
+                }
+
+                write!(res, "{}", part.to_estring()).ok()?;
+                Some(res)
+            })
+            .map(EString)
+            .expect("Cannot format Repeat ${self.0} to EString")
+    }
+}
+
+impl<T, const SEP: char, const N: usize> ParseFragment for Repeat<T, SEP, N>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let inner = split::elements::<EmptyInputAsEmptyVec>(&value, SEP)?;
+        if inner.len() != N {
+            let reason = Reason::Length {
+                expected: N,
+                actual: inner.len(),
+            };
+            return Err(Error(value, reason));
+        }
+
+        inner
+            .into_iter()
+            .map(T::parse_frag)
+            .collect::<crate::Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const SEP: char, const N: usize> crate::core::Aggregatable for Repeat<T, SEP, N>
+where
+    T: crate::core::Aggregatable,
+{
+    type Item = T::Item;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0.into_iter().flat_map(T::items).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Words = Repeat<String, ' ', 3>;
+
+    #[test]
+    fn should_parse_fixed_number_of_items() {
+        let estr = EString::from("foo bar baz");
+        match estr.parse::<Words>() {
+            Ok(res) => assert_eq!(*res, vec!["foo", "bar", "baz"]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_length_error() {
+        let estr = EString::from("foo bar");
+        match estr.parse::<Words>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("foo bar"));
+                assert_eq!(
+                    reason,
+                    Reason::Length {
+                        expected: 3,
+                        actual: 2
+                    }
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_empty_string_as_zero_items() {
+        let estr = EString::from("");
+        match estr.parse::<Repeat<String, ' ', 0>>() {
+            Ok(res) => assert!(res.is_empty()),
+            _ => unreachable!(),
+        }
+
+        let estr = EString::from("");
+        match estr.parse::<Words>() {
+            Err(Error(_, reason)) => assert_eq!(
+                reason,
+                Reason::Length {
+                    expected: 3,
+                    actual: 0
+                }
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_repeat() {
+        let words = Words::from(vec!["a".into(), "b".into(), "c".into()]);
+        assert_eq!(words.to_estring(), EString::from("a b c"));
+    }
+}
@@ -0,0 +1,120 @@
+//! Contains the implementations to pair tuple type that splits from the right
+//!
+
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for pair (A, B) tuple to split string by the last separator (`S1`).
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, RPair};
+///
+/// type AtPair<A, B> = RPair<A, '@', B>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("user@host@domain").parse::<AtPair<&str, &str>>()?;
+///     assert_eq!(res, RPair("user@host", "domain"));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct RPair<A, const S1: char, B>(pub A, pub B);
+
+impl<A, B, const S1: char> From<(A, B)> for RPair<A, S1, B> {
+    #[inline]
+    fn from((a, b): (A, B)) -> Self {
+        Self(a, b)
+    }
+}
+
+impl<A, B, const S1: char> std::fmt::Display for RPair<A, S1, B>
+where
+    A: std::fmt::Display,
+    B: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.0, S1, self.1)
+    }
+}
+
+impl<A, B, const S1: char> ToEString for RPair<A, S1, B>
+where
+    A: ToEString,
+    B: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        let mut res = String::new();
+        write!(res, "{}{}{}", self.0.to_estring(), S1, self.1.to_estring())
+            .ok()
+            .expect("Cannot parse RPair to EString");
+        EString(res)
+    }
+}
+
+impl<A, B, const S1: char> ParseFragment for RPair<A, S1, B>
+where
+    A: ParseFragment,
+    B: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        value
+            .clone()
+            .rsplit_once(S1)
+            .ok_or(Error(value, Reason::Split))
+            .and_then(|(a, b)| {
+                let (a, b) = (EString::from(a), EString::from(b));
+                let a = A::parse_frag(a.clone()).map_err(|_| Error(a, Reason::Parse))?;
+                let b = B::parse_frag(b.clone()).map_err(|_| Error(b, Reason::Parse))?;
+                Ok(Self(a, b))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type DotPair<A, B> = RPair<A, '.', B>;
+
+    #[test]
+    fn should_parse_into_pair() {
+        let estr = EString::from("path/to/file.tar.gz");
+        match estr.parse::<DotPair<&str, &str>>() {
+            Ok(res) => assert_eq!(res, RPair("path/to/file.tar", "gz")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_rest_as_pair() {
+        let estr = EString::from("file.tar.gz");
+        match estr.parse::<DotPair<DotPair<&str, &str>, &str>>() {
+            Ok(res) => assert_eq!(res, RPair(RPair("file", "tar"), "gz")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error() {
+        let estr = EString::from("file");
+        match estr.parse::<DotPair<&str, &str>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("file"));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_pair() {
+        let pair = DotPair::from((DotPair::from(("file", "tar")), "gz"));
+        assert_eq!(pair.to_estring(), EString::from("file.tar.gz"));
+    }
+}
@@ -0,0 +1,120 @@
+//! Contains the implementations to triple-tuple type that splits from the right
+//!
+
+use super::RPair;
+use crate::core::{EString, ParseFragment, ToEString};
+use std::fmt::Write;
+
+/// Wrapper for trio (A, B, C) tuple to split string by the last separators (`S1` and `S2`).
+///
+/// The string is split by the last `S2` first, and then the rest is split by the last `S1`.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, RTrio};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("a.b.c:d:e").parse::<RTrio<&str, '.', &str, ':', &str>>()?;
+///     assert_eq!(res, RTrio("a.b", "c:d", "e"));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct RTrio<A, const S1: char, B, const S2: char, C>(pub A, pub B, pub C);
+
+impl<A, B, C, const S1: char, const S2: char> From<(A, B, C)> for RTrio<A, S1, B, S2, C> {
+    #[inline]
+    fn from((a, b, c): (A, B, C)) -> Self {
+        Self(a, b, c)
+    }
+}
+
+impl<A, B, C, const S1: char, const S2: char> std::fmt::Display for RTrio<A, S1, B, S2, C>
+where
+    A: std::fmt::Display,
+    B: std::fmt::Display,
+    C: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())?;
+        f.write_char(S1)?;
+        f.write_str(&self.1.to_string())?;
+        f.write_char(S2)?;
+        f.write_str(&self.2.to_string())
+    }
+}
+
+impl<A, B, C, const S1: char, const S2: char> ToEString for RTrio<A, S1, B, S2, C>
+where
+    A: ToEString,
+    B: ToEString,
+    C: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        let mut res = String::new();
+        write!(
+            res,
+            "{}{}{}{}{}",
+            self.0.to_estring(),
+            S1,
+            self.1.to_estring(),
+            S2,
+            self.2.to_estring()
+        )
+        .ok()
+        .expect("Cannot parse RTrio to EString");
+        EString(res)
+    }
+}
+
+impl<A, const S1: char, B, const S2: char, C> ParseFragment for RTrio<A, S1, B, S2, C>
+where
+    A: ParseFragment,
+    B: ParseFragment,
+    C: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        RPair::<EString, S2, C>::parse_frag(value).and_then(|RPair(rest, c)| {
+            RPair::<A, S1, B>::parse_frag(rest).map(|RPair(a, b)| Self(a, b, c))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Reason};
+
+    type AtTrio<A, B, C> = RTrio<A, '@', B, '@', C>;
+
+    #[test]
+    fn should_parse_into_trio() {
+        let estr = EString::from("user@name@host@domain");
+        match estr.parse::<AtTrio<&str, &str, &str>>() {
+            Ok(res) => assert_eq!(res, RTrio("user@name", "host", "domain")),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error() {
+        let estr = EString::from("host@domain");
+        match estr.parse::<AtTrio<&str, &str, &str>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("host"));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_trio() {
+        let trio = RTrio::<_, '.', _, ':', _>::from(("a.b", "c", "d"));
+        assert_eq!(trio.to_estring(), EString::from("a.b.c:d"));
+    }
+}
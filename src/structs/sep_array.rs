@@ -0,0 +1,174 @@
+//! Contains the implementations to fixed-size array type
+//!
+
+use super::split::{self, Plain};
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for fixed-size array `[T; N]` to split string by a separator (`SEP`).
+///
+/// Each element is trimmed before parsing, as ``SepVec`` does. If the number of elements is not
+/// equal to `N`, returns ``Reason::Length``.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, SepArray};
+///
+/// type Rgb = SepArray<u8, ',', 3>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("255, 128, 0").parse::<Rgb>()?;
+///     assert_eq!(*res, [255, 128, 0]);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SepArray<T, const SEP: char, const N: usize>(pub [T; N]);
+
+impl<T, const SEP: char, const N: usize> std::ops::Deref for SepArray<T, SEP, N> {
+    type Target = [T; N];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const SEP: char, const N: usize> From<[T; N]> for SepArray<T, SEP, N> {
+    #[inline]
+    fn from(arr: [T; N]) -> Self {
+        Self(arr)
+    }
+}
+
+impl<T, const SEP: char, const N: usize> std::fmt::Display for SepArray<T, SEP, N>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().enumerate().try_for_each(|(i, part)| {
+            if i != 0 {
+                f.write_char(SEP)?;
+            }
+
+            write!(f, "{part}")
+        })
+    }
+}
+
+impl<T, const SEP: char, const N: usize> ToEString for SepArray<T, SEP, N>
+where
+    T: ToEString,
+{
+    fn to_estring(&self) -> EString {
+        self.0
+            .iter()
+            .enumerate()
+            .try_fold(String::new(), |mut res, (i, part)| {
+                if i != 0 {
+                    res.write_char(SEP).ok()?;
+                }
+
+                write!(res, "{}", part.to_estring()).ok()?;
+                Some(res)
+            })
+            .map(EString)
+            .expect("Cannot format SepArray ${self.0} to EString")
+    }
+}
+
+impl<T, const SEP: char, const N: usize> ParseFragment for SepArray<T, SEP, N>
+where
+    T: ParseFragment,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let parts = split::elements::<Plain>(&value, SEP)?;
+        if parts.len() != N {
+            let reason = Reason::Length {
+                expected: N,
+                actual: parts.len(),
+            };
+            return Err(Error(value, reason));
+        }
+
+        let inner = parts
+            .into_iter()
+            .map(T::parse_frag)
+            .collect::<crate::Result<Vec<_>>>()?;
+        // the length is already checked
+        <[T; N]>::try_from(inner)
+            .map(Self)
+            .map_err(|_| Error(value, Reason::Parse))
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const SEP: char, const N: usize> crate::core::Aggregatable for SepArray<T, SEP, N>
+where
+    T: crate::core::Aggregatable,
+{
+    type Item = T::Item;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0.into_iter().flat_map(T::items).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Point = SepArray<i32, ',', 3>;
+
+    #[test]
+    fn should_parse_into_array() {
+        let estr = EString::from("1, -2, 3");
+        match estr.parse::<Point>() {
+            Ok(res) => assert_eq!(res, SepArray([1, -2, 3])),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_length_error() {
+        for (input, actual) in [("1,2", 2), ("1,2,3,4", 4), ("1,x", 2)] {
+            let estr = EString::from(input);
+            match estr.parse::<Point>() {
+                Err(Error(orig, reason)) => {
+                    assert_eq!(orig, EString::from(input));
+                    assert_eq!(
+                        reason,
+                        Reason::Length {
+                            expected: 3,
+                            actual
+                        }
+                    );
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn should_throw_parse_error() {
+        let estr = EString::from("1,b,3");
+        match estr.parse::<Point>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("b"));
+                assert_eq!(reason, Reason::Parse);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_array() {
+        let arr = Point::from([1, 2, 3]);
+        assert_eq!(arr.to_estring(), EString::from("1,2,3"));
+    }
+}
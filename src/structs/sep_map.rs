@@ -0,0 +1,247 @@
+//! Contains the implementations to map type
+//!
+
+use super::collection::{DuplicatePolicy, Duplicates, LastWins, MapStorage};
+use super::split::{self, EmptyInputAsEmptyVec};
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// Wrapper for map to split string into entries by a separator (`ENTRY_SEP`), and each entry
+/// into key and value by a separator (`KV_SEP`).
+///
+/// Keys and values are trimmed before parsing, and an empty fragment is parsed as an empty map.
+///
+/// The storage (`S`) can be ``HashMap``, ``BTreeMap`` or ``OrderedMap``, or any type that
+/// implements ``MapStorage``. Duplicate keys are handled by policy (`D`): ``LastWins``,
+/// ``FirstWins`` or ``RejectDuplicates``.
+///
+/// Entries are formatted in sorted or insertion order for ordered storages, and sorted by their
+/// formatted value otherwise.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, RejectDuplicates, SepMap, ToEString};
+/// use std::collections::HashMap;
+///
+/// type Settings = SepMap<String, ',', '=', u32, HashMap<String, u32>, RejectDuplicates>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("timeout=30, retries=3").parse::<Settings>()?;
+///     assert_eq!(res.get("timeout"), Some(&30));
+///     assert_eq!(res.to_estring(), EString::from("retries=3,timeout=30"));
+///
+///     let res = EString::from("retries=1,retries=3").parse::<Settings>();
+///     assert!(res.is_err());
+///     Ok(())
+/// }
+/// ```
+///
+pub struct SepMap<K, const ENTRY_SEP: char, const KV_SEP: char, V, S = HashMap<K, V>, D = LastWins>(
+    pub S,
+    PhantomData<(K, V, D)>,
+);
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> std::fmt::Debug
+    for SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+where
+    S: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SepMap").field(&self.0).finish()
+    }
+}
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> PartialEq
+    for SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+where
+    S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> Clone
+    for SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from(self.0.clone())
+    }
+}
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> std::ops::Deref
+    for SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+{
+    type Target = S;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> From<S>
+    for SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+{
+    #[inline]
+    fn from(map: S) -> Self {
+        Self(map, PhantomData)
+    }
+}
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+where
+    S: MapStorage<K, V>,
+{
+    fn join(&self, format: impl Fn(&K, &V) -> String) -> String {
+        let mut entries = self
+            .0
+            .entries()
+            .into_iter()
+            .map(|(k, v)| format(k, v))
+            .collect::<Vec<_>>();
+        if !S::ORDERED {
+            entries.sort();
+        }
+        entries.join(ENTRY_SEP.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> std::fmt::Display
+    for SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+where
+    K: std::fmt::Display,
+    V: std::fmt::Display,
+    S: MapStorage<K, V>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.join(|k, v| format!("{k}{KV_SEP}{v}")))
+    }
+}
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> ToEString
+    for SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+where
+    K: ToEString,
+    V: ToEString,
+    S: MapStorage<K, V>,
+{
+    fn to_estring(&self) -> EString {
+        EString(self.join(|k, v| format!("{}{}{}", k.to_estring(), KV_SEP, v.to_estring())))
+    }
+}
+
+impl<K, const ENTRY_SEP: char, const KV_SEP: char, V, S, D> ParseFragment
+    for SepMap<K, ENTRY_SEP, KV_SEP, V, S, D>
+where
+    K: ParseFragment,
+    V: ParseFragment,
+    S: MapStorage<K, V>,
+    D: Duplicates,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let mut map = S::default();
+        for entry in split::elements::<EmptyInputAsEmptyVec>(&value, ENTRY_SEP)? {
+            let (k, v) = entry
+                .split_once(KV_SEP)
+                .ok_or_else(|| Error(entry.clone(), Reason::Split))?;
+            let (k, v) = (EString::from(k.trim()), EString::from(v.trim()));
+            let k = K::parse_frag(k.clone()).map_err(|_| Error(k, Reason::Parse))?;
+            let v = V::parse_frag(v.clone()).map_err(|_| Error(v, Reason::Parse))?;
+
+            if map.contains_key(&k) {
+                match D::POLICY {
+                    DuplicatePolicy::Reject => return Err(Error(entry, Reason::Duplicate)),
+                    DuplicatePolicy::FirstWins => continue,
+                    DuplicatePolicy::LastWins => {}
+                }
+            }
+            map.insert(k, v);
+        }
+        Ok(Self::from(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::{FirstWins, OrderedMap, RejectDuplicates};
+    use std::collections::BTreeMap;
+
+    type EqMap<K, V, S, D = LastWins> = SepMap<K, ',', '=', V, S, D>;
+
+    #[test]
+    fn should_parse_into_hash_map() {
+        let estr = EString::from("a = 1, b=2");
+        match estr.parse::<SepMap<&str, ',', '=', i32>>() {
+            Ok(res) => assert_eq!(*res, HashMap::from([("a", 1), ("b", 2)])),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_empty_string_as_empty_map() {
+        match EString::new().parse::<SepMap<&str, ',', '=', i32>>() {
+            Ok(res) => assert!(res.is_empty()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_handle_duplicates_by_policy() {
+        let estr = EString::from("a=1,b=2,a=3");
+
+        let res = estr.clone().parse::<EqMap<&str, i32, OrderedMap<_, _>>>();
+        assert_eq!(res.map(|m| m.to_vec()), Ok(vec![("a", 3), ("b", 2)]));
+
+        let res = estr
+            .clone()
+            .parse::<EqMap<&str, i32, OrderedMap<_, _>, FirstWins>>();
+        assert_eq!(res.map(|m| m.to_vec()), Ok(vec![("a", 1), ("b", 2)]));
+
+        let res = estr.parse::<EqMap<&str, i32, OrderedMap<_, _>, RejectDuplicates>>();
+        assert_eq!(
+            res.map(|m| m.to_vec()),
+            Err(Error(EString::from("a=3"), Reason::Duplicate))
+        );
+    }
+
+    #[test]
+    fn should_throw_split_error_without_separator() {
+        let estr = EString::from("a=1,b");
+        match estr.parse::<SepMap<&str, ',', '=', i32>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("b"));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_map_in_storage_order() {
+        let estr = EString::from("c=1,a=2,b=3");
+
+        let res = estr.clone().parse::<EqMap<&str, i32, BTreeMap<_, _>>>();
+        assert_eq!(
+            res.map(|m| m.to_estring()),
+            Ok(EString::from("a=2,b=3,c=1"))
+        );
+
+        let res = estr.clone().parse::<EqMap<&str, i32, HashMap<_, _>>>();
+        assert_eq!(
+            res.map(|m| m.to_estring()),
+            Ok(EString::from("a=2,b=3,c=1"))
+        );
+
+        let res = estr.parse::<EqMap<&str, i32, OrderedMap<_, _>>>();
+        assert_eq!(res.map(|m| m.to_string()), Ok(String::from("c=1,a=2,b=3")));
+    }
+}
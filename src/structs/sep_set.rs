@@ -0,0 +1,202 @@
+//! Contains the implementations to set type
+//!
+
+use super::collection::{DuplicatePolicy, Duplicates, LastWins, SetStorage};
+use super::split::{self, EmptyInputAsEmptyVec};
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+/// Wrapper for set to split string by a separator (`SEP`).
+///
+/// Each element is trimmed before parsing, and an empty fragment is parsed as an empty set.
+///
+/// The storage (`S`) can be ``HashSet`` or ``BTreeSet``, or any type that implements
+/// ``SetStorage``. Duplicate elements are handled by policy (`D`): ``LastWins``, ``FirstWins``
+/// or ``RejectDuplicates``.
+///
+/// Elements are formatted in sorted order.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, SepSet};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("web, db, web").parse::<SepSet<&str, ','>>()?;
+///     assert_eq!(res.len(), 2);
+///     assert!(res.contains("db"));
+///     Ok(())
+/// }
+/// ```
+///
+pub struct SepSet<T, const SEP: char, S = HashSet<T>, D = LastWins>(pub S, PhantomData<(T, D)>);
+
+impl<T, const SEP: char, S, D> std::fmt::Debug for SepSet<T, SEP, S, D>
+where
+    S: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SepSet").field(&self.0).finish()
+    }
+}
+
+impl<T, const SEP: char, S, D> PartialEq for SepSet<T, SEP, S, D>
+where
+    S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, const SEP: char, S, D> Clone for SepSet<T, SEP, S, D>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from(self.0.clone())
+    }
+}
+
+impl<T, const SEP: char, S, D> std::ops::Deref for SepSet<T, SEP, S, D> {
+    type Target = S;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const SEP: char, S, D> From<S> for SepSet<T, SEP, S, D> {
+    #[inline]
+    fn from(set: S) -> Self {
+        Self(set, PhantomData)
+    }
+}
+
+impl<T, const SEP: char, S, D> SepSet<T, SEP, S, D>
+where
+    S: SetStorage<T>,
+{
+    fn join(&self, format: impl Fn(&T) -> String) -> String {
+        let mut elems = self
+            .0
+            .elements()
+            .into_iter()
+            .map(format)
+            .collect::<Vec<_>>();
+        if !S::ORDERED {
+            elems.sort();
+        }
+        elems.join(SEP.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl<T, const SEP: char, S, D> std::fmt::Display for SepSet<T, SEP, S, D>
+where
+    T: std::fmt::Display,
+    S: SetStorage<T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.join(ToString::to_string))
+    }
+}
+
+impl<T, const SEP: char, S, D> ToEString for SepSet<T, SEP, S, D>
+where
+    T: ToEString,
+    S: SetStorage<T>,
+{
+    fn to_estring(&self) -> EString {
+        EString(self.join(|elem| elem.to_estring().0))
+    }
+}
+
+impl<T, const SEP: char, S, D> ParseFragment for SepSet<T, SEP, S, D>
+where
+    T: ParseFragment,
+    S: SetStorage<T>,
+    D: Duplicates,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let mut set = S::default();
+        for elem in split::elements::<EmptyInputAsEmptyVec>(&value, SEP)? {
+            let parsed = T::parse_frag(elem.clone())?;
+            if set.contains(&parsed) {
+                match D::POLICY {
+                    DuplicatePolicy::Reject => return Err(Error(elem, Reason::Duplicate)),
+                    DuplicatePolicy::FirstWins => continue,
+                    DuplicatePolicy::LastWins => {}
+                }
+            }
+            set.insert(parsed);
+        }
+        Ok(Self::from(set))
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const SEP: char, S, D> crate::core::Aggregatable for SepSet<T, SEP, S, D>
+where
+    T: crate::core::Aggregatable + Clone,
+    S: SetStorage<T>,
+{
+    type Item = T::Item;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0
+            .elements()
+            .into_iter()
+            .cloned()
+            .flat_map(T::items)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::RejectDuplicates;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn should_parse_into_hash_set() {
+        let estr = EString::from("1, 2, 3, 2");
+        match estr.parse::<SepSet<i32, ','>>() {
+            Ok(res) => assert_eq!(*res, HashSet::from([1, 2, 3])),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_empty_string_as_empty_set() {
+        match EString::new().parse::<SepSet<i32, ','>>() {
+            Ok(res) => assert!(res.is_empty()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_duplicate_error() {
+        let estr = EString::from("1,2,1");
+        match estr.parse::<SepSet<i32, ',', BTreeSet<i32>, RejectDuplicates>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("1"));
+                assert_eq!(reason, Reason::Duplicate);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_set_in_sorted_order() {
+        let set = SepSet::<_, ','>::from(HashSet::from(["c", "a", "b"]));
+        assert_eq!(set.to_estring(), EString::from("a,b,c"));
+        let set = SepSet::<_, ',', BTreeSet<_>>::from(BTreeSet::from([3, 1, 2]));
+        assert_eq!(set.to_string(), "1,2,3");
+    }
+}
@@ -0,0 +1,166 @@
+//! Contains the implementations to tuple types of arity 2 to 12
+//!
+
+use super::split::{self, Plain};
+use crate::core::{EString, ParseFragment, ToEString};
+use crate::{Error, Reason};
+use std::fmt::Write;
+
+/// Wrapper for tuples of arity 2 to 12 to split string by a single separator (`SEP`).
+///
+/// Each element is trimmed before parsing, as ``SepVec`` does. If the number of elements is not
+/// equal to the arity of the tuple, returns ``Reason::Length``.
+///
+/// Use ``Pair`` or ``Trio`` if you need different separators.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, SepTuple};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("db, 5432, true").parse::<SepTuple<(&str, u16, bool), ','>>()?;
+///     assert_eq!(*res, ("db", 5432, true));
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SepTuple<T, const SEP: char>(pub T);
+
+impl<T, const SEP: char> std::ops::Deref for SepTuple<T, SEP> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const SEP: char> From<T> for SepTuple<T, SEP> {
+    #[inline]
+    fn from(tuple: T) -> Self {
+        Self(tuple)
+    }
+}
+
+#[doc(hidden)]
+macro_rules! sep_tuple_impl {
+    ($($len:literal => ($($T:ident $i:tt),+);)+) => {
+        $(
+            impl<$($T,)+ const SEP: char> std::fmt::Display for SepTuple<($($T,)+), SEP>
+            where
+                $($T: std::fmt::Display,)+
+            {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    let parts = [$(self.0.$i.to_string(),)+];
+                    f.write_str(&parts.join(SEP.encode_utf8(&mut [0; 4])))
+                }
+            }
+
+            impl<$($T,)+ const SEP: char> ToEString for SepTuple<($($T,)+), SEP>
+            where
+                $($T: ToEString,)+
+            {
+                fn to_estring(&self) -> EString {
+                    let mut res = String::new();
+                    $(
+                        if $i != 0 {
+                            res.write_char(SEP)
+                                .ok()
+                                .expect("Cannot format SepTuple to EString");
+                        }
+                        res.push_str(&self.0.$i.to_estring());
+                    )+
+                    EString(res)
+                }
+            }
+
+            impl<$($T,)+ const SEP: char> ParseFragment for SepTuple<($($T,)+), SEP>
+            where
+                $($T: ParseFragment,)+
+            {
+                fn parse_frag(value: EString) -> crate::Result<Self> {
+                    let parts = split::elements::<Plain>(&value, SEP)?;
+                    if parts.len() != $len {
+                        let reason = Reason::Length {
+                            expected: $len,
+                            actual: parts.len(),
+                        };
+                        return Err(Error(value, reason));
+                    }
+
+                    let mut parts = parts.into_iter();
+                    Ok(Self(($($T::parse_frag(parts.next().unwrap_or_default())?,)+)))
+                }
+            }
+        )+
+    };
+}
+
+#[rustfmt::skip]
+sep_tuple_impl! {
+    2 => (A 0, B 1);
+    3 => (A 0, B 1, C 2);
+    4 => (A 0, B 1, C 2, D 3);
+    5 => (A 0, B 1, C 2, D 3, E 4);
+    6 => (A 0, B 1, C 2, D 3, E 4, F 5);
+    7 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    8 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    9 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    10 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    11 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    12 => (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_into_tuple() {
+        let estr = EString::from("1, two, 3.5, on");
+        match estr.parse::<SepTuple<(u8, &str, f32, bool), ','>>() {
+            Ok(res) => assert_eq!(*res, (1, "two", 3.5, true)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_into_largest_tuple() {
+        type Tuple12 = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+        let estr = EString::from("1 2 3 4 5 6 7 8 9 10 11 12");
+        match estr.parse::<SepTuple<Tuple12, ' '>>() {
+            Ok(res) => assert_eq!(*res, (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_length_error() {
+        let estr = EString::from("1,2,3");
+        match estr.parse::<SepTuple<(u8, u8), ','>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("1,2,3"));
+                assert_eq!(
+                    reason,
+                    Reason::Length {
+                        expected: 2,
+                        actual: 3
+                    }
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_tuple() {
+        let tuple = SepTuple::<_, ':'>::from(("a", 1, false));
+        assert_eq!(tuple.to_estring(), EString::from("a:1:false"));
+        assert_eq!(tuple.to_string(), "a:1:false");
+    }
+}
@@ -6,6 +6,9 @@ use std::fmt::Write;
 
 /// Wrapper for ``Vec`` to split string by a separator (`SEP`).
 ///
+/// Each element is trimmed before parsing. Use ``RawSepVec`` to keep whitespaces, or
+/// ``SepVecWith`` to customize splitting.
+///
 /// **NOTE**: Required the enabling of the `structs` feature.
 ///
 /// # Examples
@@ -51,7 +54,7 @@ where
                 f.write_char(SEP)?;
             }
 
-            write!(f, "{}", part)
+            write!(f, "{part}")
         })
     }
 }
@@ -120,7 +123,7 @@ mod tests {
         match estr.parse::<CommaVec<&str>>() {
             Ok(res) => assert_eq!(*res, vec!["a", "b", "c", "d", "e"]),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -132,7 +135,7 @@ d,e";
         match estr.parse::<CommaVec<&str>>() {
             Ok(res) => assert_eq!(*res, vec!["a", "b", "c", "d", "e"]),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -148,7 +151,7 @@ d,e";
                 ])
             ),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -157,7 +160,7 @@ d,e";
         match estr.parse::<CommaVec<i32>>() {
             Ok(res) => assert_eq!(*res, vec![1, 2, 3, 4, 5]),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -169,7 +172,7 @@ d,e";
                 assert_eq!(reason, Reason::Parse);
             }
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
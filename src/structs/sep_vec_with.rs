@@ -0,0 +1,384 @@
+//! Contains the implementations to vec type with a custom split mode
+//!
+
+use super::split::{self, BracketAware, QuoteAware, Raw, SplitMode};
+use crate::core::{EString, ParseFragment, ToEString};
+use std::fmt::Write;
+use std::marker::PhantomData;
+
+/// Wrapper for ``Vec`` to split string by a separator (`SEP`) using split mode (`M`).
+///
+/// See ``SplitMode`` to find out which options are available.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, QuotedSepVec, ToEString};
+///
+/// type CommaVec<T> = QuotedSepVec<T, ','>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from(r#""a,b", c\,d"#).parse::<CommaVec<String>>()?;
+///     assert_eq!(*res, vec![String::from("a,b"), String::from("c,d")]);
+///     assert_eq!(res.to_estring(), EString::from(r#""a,b","c,d""#));
+///     Ok(())
+/// }
+/// ```
+///
+pub struct SepVecWith<T, const SEP: char, M>(pub Vec<T>, PhantomData<M>);
+
+/// Wrapper for ``Vec`` that respects quotes and backslash escapes when it splits string by a
+/// separator (`SEP`).
+///
+/// An empty vec is formatted as an empty string and an empty element as `""`, so both are parsed
+/// back to the same value.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+pub type QuotedSepVec<T, const SEP: char> = SepVecWith<T, SEP, QuoteAware>;
+
+/// Wrapper for ``Vec`` that doesn't trim elements when it splits string by a separator (`SEP`).
+///
+/// Elements are not quoted, so an empty vec and a vec with one empty element are both formatted
+/// as an empty string, which is parsed back as a vec with one empty element.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, RawSepVec};
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from(" a , b").parse::<RawSepVec<&str, ','>>()?;
+///     assert_eq!(*res, vec![" a ", " b"]);
+///     Ok(())
+/// }
+/// ```
+///
+pub type RawSepVec<T, const SEP: char> = SepVecWith<T, SEP, Raw>;
+
+/// Wrapper for ``Vec`` that skips over balanced brackets when it splits string by a separator
+/// (`SEP`), so nested lists can reuse the same separator.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{Bracketed, EString, GroupedSepVec};
+///
+/// type List<T> = Bracketed<GroupedSepVec<T, ','>, '[', ']'>;
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from("[1,2],[3]").parse::<GroupedSepVec<List<i32>, ','>>()?;
+///     assert_eq!(res[0].as_slice(), [1, 2]);
+///     assert_eq!(res[1].as_slice(), [3]);
+///     Ok(())
+/// }
+/// ```
+///
+pub type GroupedSepVec<T, const SEP: char> = SepVecWith<T, SEP, BracketAware>;
+
+impl<T, const SEP: char, M> std::fmt::Debug for SepVecWith<T, SEP, M>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SepVecWith").field(&self.0).finish()
+    }
+}
+
+impl<T, const SEP: char, M> PartialEq for SepVecWith<T, SEP, M>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, const SEP: char, M> Clone for SepVecWith<T, SEP, M>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from(self.0.clone())
+    }
+}
+
+impl<T, const SEP: char, M> std::ops::Deref for SepVecWith<T, SEP, M> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const SEP: char, M> From<Vec<T>> for SepVecWith<T, SEP, M> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        Self(vec, PhantomData)
+    }
+}
+
+impl<T, const SEP: char, M> std::fmt::Display for SepVecWith<T, SEP, M>
+where
+    T: std::fmt::Display,
+    M: SplitMode,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().enumerate().try_for_each(|(i, part)| {
+            if i != 0 {
+                f.write_char(SEP)?;
+            }
+
+            f.write_str(&split::quote::<M>(part.to_string(), SEP))
+        })
+    }
+}
+
+impl<T, const SEP: char, M> ToEString for SepVecWith<T, SEP, M>
+where
+    T: ToEString,
+    M: SplitMode,
+{
+    fn to_estring(&self) -> EString {
+        self.0
+            .iter()
+            .enumerate()
+            .try_fold(String::new(), |mut res, (i, part)| {
+                if i != 0 {
+                    res.write_char(SEP).ok()?;
+                }
+
+                res.write_str(&split::quote::<M>(part.to_estring().0, SEP))
+                    .ok()?;
+                Some(res)
+            })
+            .map(EString)
+            .expect("Cannot format SepVecWith ${self.0} to EString")
+    }
+}
+
+impl<T, const SEP: char, M> ParseFragment for SepVecWith<T, SEP, M>
+where
+    T: ParseFragment,
+    M: SplitMode,
+{
+    fn parse_frag(value: EString) -> crate::Result<Self> {
+        let inner = split::elements::<M>(&value, SEP)?
+            .into_iter()
+            .map(T::parse_frag)
+            .collect::<crate::Result<Vec<_>>>()?;
+        Ok(Self::from(inner))
+    }
+}
+
+#[cfg(feature = "aggs")]
+impl<T, const SEP: char, M> crate::core::Aggregatable for SepVecWith<T, SEP, M>
+where
+    T: crate::core::Aggregatable,
+{
+    type Item = T::Item;
+
+    fn items(self) -> Vec<Self::Item> {
+        self.0.into_iter().flat_map(T::items).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::Plain;
+    use crate::{Error, Reason};
+
+    type CommaVec<T> = QuotedSepVec<T, ','>;
+    type SemiVec<T> = QuotedSepVec<T, ';'>;
+
+    #[test]
+    fn should_parse_quoted_elements() {
+        let estr = EString::from(r#"a, "b,c" ,'d,e', f\,g"#);
+        match estr.parse::<CommaVec<String>>() {
+            Ok(res) => assert_eq!(*res, vec!["a", "b,c", "d,e", "f,g"]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_keep_whitespaces_inside_quotes() {
+        let estr = EString::from(r#"" a ", b"#);
+        match estr.parse::<CommaVec<String>>() {
+            Ok(res) => assert_eq!(*res, vec![" a ", "b"]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_naively_in_plain_mode() {
+        let estr = EString::from(r#""a,b",c"#);
+        match estr.parse::<SepVecWith<&str, ',', Plain>>() {
+            Ok(res) => assert_eq!(*res, vec![r#""a"#, r#"b""#, "c"]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_nested_lists_with_same_separator() {
+        use crate::structs::Bracketed;
+
+        type List<T> = Bracketed<GroupedSepVec<T, ','>, '[', ']'>;
+
+        let estr = EString::from("[[1,2], [3]], [[4,5,6]]");
+        match estr.parse::<GroupedSepVec<List<List<i32>>, ','>>() {
+            Ok(res) => {
+                let res = res
+                    .iter()
+                    .map(|list| list.iter().map(|l| l.to_vec()).collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+                assert_eq!(res, vec![vec![vec![1, 2], vec![3]], vec![vec![4, 5, 6]]]);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_split_error_on_unbalanced_brackets() {
+        let estr = EString::from("[1,2,3");
+        match estr.parse::<GroupedSepVec<String, ','>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("[1,2,3"));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_keep_whitespaces_in_raw_vec() {
+        use crate::structs::Pair;
+
+        let estr = EString::from("a= 1,b=  2");
+        match estr.parse::<RawSepVec<Pair<&str, '=', &str>, ','>>() {
+            Ok(res) => assert_eq!(*res, vec![Pair("a", " 1"), Pair("b", "  2")]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[cfg(feature = "low-level")]
+    #[test]
+    fn should_trim_explicitly_in_raw_vec() {
+        use crate::low::Trim;
+
+        let estr = EString::from(" 1 , 2");
+        match estr.parse::<RawSepVec<Trim<i32>, ','>>() {
+            Ok(res) => assert_eq!(*res, vec![Trim(1), Trim(2)]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_format_raw_vec() {
+        let vec = RawSepVec::<_, ','>::from(vec![" a ", "b "]);
+        let estr = vec.to_estring();
+        assert_eq!(estr, EString::from(" a ,b "));
+        assert_eq!(estr.parse::<RawSepVec<&str, ','>>(), Ok(vec));
+    }
+
+    #[test]
+    fn should_parse_formatted_empty_raw_vec_as_one_empty_element() {
+        let vec = RawSepVec::<&str, ','>::from(vec![]);
+        let estr = vec.to_estring();
+        assert_eq!(estr, EString::new());
+        assert_eq!(
+            estr.parse::<RawSepVec<&str, ','>>(),
+            Ok(RawSepVec::from(vec![""]))
+        );
+    }
+
+    #[test]
+    fn should_skip_trailing_separator() {
+        use crate::structs::SkipEmpty;
+
+        let estr = EString::from("1,2,3,");
+        match estr.parse::<SepVecWith<i32, ',', SkipEmpty>>() {
+            Ok(res) => assert_eq!(*res, vec![1, 2, 3]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_throw_empty_error_on_empty_element() {
+        use crate::structs::RejectEmpty;
+
+        let estr = EString::from("1,,3");
+        match estr.parse::<SepVecWith<i32, ',', RejectEmpty>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from("1,,3"));
+                assert_eq!(reason, Reason::Empty);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_empty_input_as_empty_vec() {
+        use crate::structs::EmptyInputAsEmptyVec;
+
+        type NumVec = SepVecWith<i32, ',', EmptyInputAsEmptyVec>;
+
+        match EString::new().parse::<NumVec>() {
+            Ok(res) => assert_eq!(*res, vec![]),
+            _ => unreachable!(),
+        }
+        assert_eq!(NumVec::from(vec![]).to_estring(), EString::new());
+    }
+
+    #[test]
+    fn should_throw_split_error_on_unterminated_quote() {
+        let estr = EString::from(r#"a,"b,c"#);
+        match estr.parse::<CommaVec<String>>() {
+            Err(Error(orig, reason)) => {
+                assert_eq!(orig, EString::from(r#"a,"b,c"#));
+                assert_eq!(reason, Reason::Split);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn should_parse_formatted_vec_back() {
+        let vec = CommaVec::from(vec![
+            String::from("a,b"),
+            String::from(" c "),
+            String::from(r#"d"e"#),
+            String::new(),
+            String::from(r"f'g\h"),
+        ]);
+        let estr = vec.to_estring();
+        assert_eq!(estr, EString::from(r#""a,b"," c ","d\"e","","f'g\\h""#));
+        assert_eq!(estr.parse::<CommaVec<String>>(), Ok(vec));
+    }
+
+    #[test]
+    fn should_parse_formatted_empty_vec_back() {
+        for vec in [vec![], vec![String::new()]] {
+            let vec = CommaVec::from(vec);
+            let estr = vec.to_estring();
+            assert_eq!(estr.parse::<CommaVec<String>>(), Ok(vec));
+        }
+    }
+
+    #[test]
+    fn should_parse_formatted_nested_vec_back() {
+        let vec = SemiVec::from(vec![
+            CommaVec::from(vec![String::from("a;b"), String::from("c")]),
+            CommaVec::from(vec![String::from("d,e")]),
+        ]);
+        let estr = vec.to_estring();
+        assert_eq!(estr, EString::from(r#""a;b,c";"\"d,e\"""#));
+        assert_eq!(estr.parse::<SemiVec<CommaVec<String>>>(), Ok(vec));
+    }
+}
@@ -0,0 +1,383 @@
+//! Contains the split modes that customize how ``SepVecWith`` splits a fragment
+//!
+
+use crate::core::EString;
+use crate::{Error, Reason};
+
+/// Describes how a fragment is split into elements by ``SepVecWith``.
+///
+/// All options are disabled by default, so an empty implementation behaves like ``SepVec``.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use estring::{EString, SepVecWith, SplitMode};
+///
+/// struct MyMode;
+///
+/// impl SplitMode for MyMode {
+///     const QUOTES: bool = true;
+/// }
+///
+/// fn main() -> estring::Result<()> {
+///     let res = EString::from(r#""a,b",c"#).parse::<SepVecWith<String, ',', MyMode>>()?;
+///     assert_eq!(*res, vec![String::from("a,b"), String::from("c")]);
+///     Ok(())
+/// }
+/// ```
+///
+pub trait SplitMode {
+    /// Don't split inside `"..."`, `'...'` and after a backslash. Each element is unquoted
+    /// after splitting, and elements are quoted back when formatting.
+    const QUOTES: bool = false;
+
+    /// Don't split inside balanced `(...)`, `[...]` and `{...}`.
+    const BRACKETS: bool = false;
+
+    /// What to do with empty elements, e.g. in `a,,b,`.
+    const EMPTY: EmptyPolicy = EmptyPolicy::Keep;
+
+    /// Parse an empty fragment as an empty vec instead of a vec with one empty element.
+    const EMPTY_INPUT_AS_EMPTY_VEC: bool = false;
+
+    /// Trim whitespaces around each element. Disable it to keep significant whitespaces and
+    /// trim explicitly with ``Trim`` where needed.
+    const TRIM: bool = true;
+}
+
+/// The policy for empty elements after splitting.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EmptyPolicy {
+    /// Keep empty elements and parse them as `T`.
+    Keep,
+    /// Skip empty elements, so an empty fragment is parsed as an empty vec.
+    Skip,
+    /// Fail with ``Reason::Empty`` on the first empty element.
+    Reject,
+}
+
+/// Naive split mode, the same behavior as ``SepVec`` has.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Plain;
+
+impl SplitMode for Plain {}
+
+/// Split mode that respects `"..."`, `'...'` and backslash escapes.
+///
+/// An empty fragment is parsed as an empty vec, because an empty element is formatted as `""`.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct QuoteAware;
+
+impl SplitMode for QuoteAware {
+    const QUOTES: bool = true;
+    const EMPTY_INPUT_AS_EMPTY_VEC: bool = true;
+}
+
+/// Split mode that respects balanced `(...)`, `[...]` and `{...}`, so nested lists can reuse
+/// the same separator.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BracketAware;
+
+impl SplitMode for BracketAware {
+    const BRACKETS: bool = true;
+}
+
+/// Split mode that doesn't trim elements.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Raw;
+
+impl SplitMode for Raw {
+    const TRIM: bool = false;
+}
+
+/// Split mode that skips empty elements, so trailing separators are allowed.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SkipEmpty;
+
+impl SplitMode for SkipEmpty {
+    const EMPTY: EmptyPolicy = EmptyPolicy::Skip;
+}
+
+/// Split mode that fails on empty elements, but parses an empty fragment as an empty vec.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RejectEmpty;
+
+impl SplitMode for RejectEmpty {
+    const EMPTY: EmptyPolicy = EmptyPolicy::Reject;
+    const EMPTY_INPUT_AS_EMPTY_VEC: bool = true;
+}
+
+/// Split mode that keeps empty elements, but parses an empty fragment as an empty vec.
+///
+/// **NOTE**: Required the enabling of the `structs` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EmptyInputAsEmptyVec;
+
+impl SplitMode for EmptyInputAsEmptyVec {
+    const EMPTY_INPUT_AS_EMPTY_VEC: bool = true;
+}
+
+/// Returns byte indices of all separators that should split the `value`.
+///
+/// Returns `None` if the `value` contains an unterminated quote, a trailing backslash or
+/// unbalanced brackets.
+pub(crate) fn separators<M: SplitMode>(value: &str, sep: char) -> Option<Vec<usize>> {
+    let mut res = Vec::new();
+    let mut quote = None;
+    let mut escaped = false;
+    let mut closers = Vec::new();
+
+    for (i, c) in value.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some('\'') => {}
+            _ if M::QUOTES && c == '\\' => escaped = true,
+            None if M::QUOTES && (c == '"' || c == '\'') => quote = Some(c),
+            None if M::BRACKETS && matches!(c, ')' | ']' | '}') => {
+                closers.pop().filter(|&closer| closer == c)?;
+            }
+            None if c == sep && closers.is_empty() => res.push(i),
+            None if M::BRACKETS => closers.extend(closing_bracket(c)),
+            _ => {}
+        }
+    }
+
+    if quote.is_some() || escaped || !closers.is_empty() {
+        None
+    } else {
+        Some(res)
+    }
+}
+
+fn closing_bracket(c: char) -> Option<char> {
+    match c {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        _ => None,
+    }
+}
+
+/// Splits the `value` by all separators that [separators] returns.
+pub(crate) fn split<M: SplitMode>(value: &str, sep: char) -> Option<Vec<&str>> {
+    separators::<M>(value, sep).map(|seps| {
+        let mut start = 0;
+        let mut res = Vec::with_capacity(seps.len() + 1);
+        for i in seps {
+            res.push(&value[start..i]);
+            start = i + sep.len_utf8();
+        }
+        res.push(&value[start..]);
+        res
+    })
+}
+
+/// Splits the `value` on the first separator that [separators] returns.
+pub(crate) fn split_once<M: SplitMode>(value: &str, sep: char) -> Option<(&str, &str)> {
+    separators::<M>(value, sep)?
+        .first()
+        .map(|&i| (&value[..i], &value[i + sep.len_utf8()..]))
+}
+
+/// Splits the `value` into trimmed (if needed) and unquoted elements according to the mode.
+pub(crate) fn elements<M: SplitMode>(value: &EString, sep: char) -> crate::Result<Vec<EString>> {
+    let parts = split::<M>(value, sep)
+        .ok_or_else(|| Error(value.clone(), Reason::Split))?
+        .into_iter()
+        .map(|part| if M::TRIM { part.trim() } else { part })
+        .collect::<Vec<_>>();
+
+    if M::EMPTY_INPUT_AS_EMPTY_VEC && parts == [""] {
+        return Ok(Vec::new());
+    }
+
+    let mut res = Vec::with_capacity(parts.len());
+    for part in parts {
+        if part.is_empty() {
+            match M::EMPTY {
+                EmptyPolicy::Keep => {}
+                EmptyPolicy::Skip => continue,
+                EmptyPolicy::Reject => return Err(Error(value.clone(), Reason::Empty)),
+            }
+        }
+        res.push(unquote::<M>(part));
+    }
+    Ok(res)
+}
+
+/// Removes quotes and backslash escapes from the element if the mode requires it.
+pub(crate) fn unquote<M: SplitMode>(value: &str) -> EString {
+    if !M::QUOTES {
+        return EString::from(value);
+    }
+
+    let mut res = String::with_capacity(value.len());
+    let mut quote = None;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some('\'') => res.push(c),
+            _ if c == '\\' => res.extend(chars.next()),
+            None if c == '"' || c == '\'' => quote = Some(c),
+            _ => res.push(c),
+        }
+    }
+    EString(res)
+}
+
+/// Wraps the element into double quotes if the mode requires it and the element cannot be
+/// parsed back as is.
+pub(crate) fn quote<M: SplitMode>(value: String, sep: char) -> String {
+    let needs_quotes = value.is_empty()
+        || (M::TRIM && value.trim() != value)
+        || value.contains(&[sep, '"', '\'', '\\'][..]);
+    if !M::QUOTES || !needs_quotes {
+        return value;
+    }
+
+    let mut res = String::with_capacity(value.len() + 2);
+    res.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            res.push('\\');
+        }
+        res.push(c);
+    }
+    res.push('"');
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_split_naively_in_plain_mode() {
+        assert_eq!(
+            split::<Plain>(r#""a,b",c"#, ','),
+            Some(vec![r#""a"#, r#"b""#, "c"])
+        );
+    }
+
+    #[test]
+    fn should_skip_separators_inside_quotes_and_escapes() {
+        assert_eq!(
+            split::<QuoteAware>(r#""a,b",'c,d',e\,f"#, ','),
+            Some(vec![r#""a,b""#, "'c,d'", r"e\,f"])
+        );
+    }
+
+    #[test]
+    fn should_fail_on_unterminated_quote() {
+        assert_eq!(split::<QuoteAware>(r#""a,b,c"#, ','), None);
+        assert_eq!(split::<QuoteAware>(r"a,b\", ','), None);
+    }
+
+    #[test]
+    fn should_skip_separators_inside_brackets() {
+        assert_eq!(
+            split::<BracketAware>("[1,2],(3,{4,5}),6", ','),
+            Some(vec!["[1,2]", "(3,{4,5})", "6"])
+        );
+    }
+
+    #[test]
+    fn should_fail_on_unbalanced_brackets() {
+        assert_eq!(split::<BracketAware>("[1,2,3", ','), None);
+        assert_eq!(split::<BracketAware>("[1,2),3", ','), None);
+        assert_eq!(split::<BracketAware>("1,2],3", ','), None);
+    }
+
+    #[test]
+    fn should_keep_whitespaces_in_raw_mode() {
+        let estr = EString::from(" a , b");
+        assert_eq!(
+            elements::<Raw>(&estr, ','),
+            Ok(vec![EString::from(" a "), EString::from(" b")])
+        );
+    }
+
+    #[test]
+    fn should_handle_empty_elements_by_policy() {
+        let estr = EString::from("a,,b, ");
+        let estrs = |v: &[&str]| v.iter().copied().map(EString::from).collect::<Vec<_>>();
+
+        assert_eq!(
+            elements::<Plain>(&estr, ','),
+            Ok(estrs(&["a", "", "b", ""]))
+        );
+        assert_eq!(elements::<SkipEmpty>(&estr, ','), Ok(estrs(&["a", "b"])));
+        assert_eq!(
+            elements::<RejectEmpty>(&estr, ','),
+            Err(Error(estr.clone(), Reason::Empty))
+        );
+    }
+
+    #[test]
+    fn should_handle_empty_input_by_policy() {
+        let estr = EString::from(" ");
+
+        assert_eq!(elements::<Plain>(&estr, ','), Ok(vec![EString::new()]));
+        assert_eq!(elements::<SkipEmpty>(&estr, ','), Ok(vec![]));
+        assert_eq!(elements::<RejectEmpty>(&estr, ','), Ok(vec![]));
+        assert_eq!(elements::<EmptyInputAsEmptyVec>(&estr, ','), Ok(vec![]));
+    }
+
+    #[test]
+    fn should_keep_quoted_empty_element() {
+        struct QuotedSkipEmpty;
+
+        impl SplitMode for QuotedSkipEmpty {
+            const QUOTES: bool = true;
+            const EMPTY: EmptyPolicy = EmptyPolicy::Skip;
+        }
+
+        let estr = EString::from(r#"a,"",b,"#);
+        assert_eq!(
+            elements::<QuotedSkipEmpty>(&estr, ','),
+            Ok(vec![EString::from("a"), EString::new(), EString::from("b")])
+        );
+    }
+
+    #[test]
+    fn should_unquote_element() {
+        assert_eq!(
+            unquote::<QuoteAware>(r#""a \"b\" \\" 'c\d'"#),
+            EString::from(r#"a "b" \ c\d"#)
+        );
+    }
+
+    #[test]
+    fn should_quote_element_only_if_needed() {
+        assert_eq!(quote::<QuoteAware>(String::from("abc"), ','), "abc");
+        assert_eq!(quote::<QuoteAware>(String::from("a,c"), ','), r#""a,c""#);
+        assert_eq!(quote::<QuoteAware>(String::from(" a"), ','), r#"" a""#);
+        assert_eq!(
+            quote::<QuoteAware>(String::from(r#"a"\"#), ','),
+            r#""a\"\\""#
+        );
+        assert_eq!(quote::<Plain>(String::from("a,c"), ','), "a,c");
+    }
+}
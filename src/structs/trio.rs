@@ -94,7 +94,7 @@ mod tests {
         match estr.parse::<EqTrio<&str, &str, &str>>() {
             Ok(res) => assert_eq!((res.0, res.1, res.2), ("hello", "world", "hello")),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -103,7 +103,7 @@ mod tests {
         match estr.parse::<Trio<&str, '-', &str, '^', &str>>() {
             Ok(res) => assert_eq!((res.0, res.1, res.2), ("hello", "world", "hello")),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]
@@ -112,7 +112,7 @@ mod tests {
         match estr.parse::<EqTrio<&str, &str, EqTrio<&str, &str, &str>>>() {
             Ok(res) => assert_eq!(res, Trio("hello", "world", Trio("hello", "world", "hello"))),
             _ => unreachable!(),
-        };
+        }
     }
 
     #[test]